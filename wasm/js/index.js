@@ -1,11 +1,15 @@
 import wasm from "../dist/wasm.js";
 
 const {
+    init,
+    selfTest,
     initThreadPool: wasmInitThreadPool,
     Address,
+    CreditsRecord,
     Execution,
     ExecutionResponse,
     Field,
+    Mnemonic,
     OfflineQuery,
     Private,
     PrivateKey,
@@ -20,6 +24,28 @@ const {
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
+    verifyBatchJson,
+    verifyAllStrings,
+    verifySignature,
+    encryptBytes,
+    decryptBytes,
+    verifyFundsAttestation,
+    filterRecordsMinValue,
+    selectRecords,
+    simulateSpend,
+    detectDuplicateNonces,
+    distinctOwners,
+    validateRecords,
+    parseU64Literal,
+    toU64Literal,
+    parsePaymentUri,
+    parseDeepLink,
+    digestHex,
+    memoTag,
+    diagnoseKeyString,
+    validateMnemonic,
+    viewKeyFromPrivateKeyString,
+    sortAddresses,
 } = await wasm({
     importHook: () => {
         return new URL("assets/aleo_wasm.wasm", import.meta.url);
@@ -37,11 +63,15 @@ async function initThreadPool(threads) {
 }
 
 export {
+    init,
+    selfTest,
     initThreadPool,
     Address,
+    CreditsRecord,
     Execution,
     ExecutionResponse,
     Field,
+    Mnemonic,
     OfflineQuery,
     PrivateKey,
     PrivateKeyCiphertext,
@@ -55,4 +85,26 @@ export {
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
+    verifyBatchJson,
+    verifyAllStrings,
+    verifySignature,
+    encryptBytes,
+    decryptBytes,
+    verifyFundsAttestation,
+    filterRecordsMinValue,
+    selectRecords,
+    simulateSpend,
+    detectDuplicateNonces,
+    distinctOwners,
+    validateRecords,
+    parseU64Literal,
+    toU64Literal,
+    parsePaymentUri,
+    parseDeepLink,
+    digestHex,
+    memoTag,
+    diagnoseKeyString,
+    validateMnemonic,
+    viewKeyFromPrivateKeyString,
+    sortAddresses,
 };
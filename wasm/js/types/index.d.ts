@@ -6,10 +6,14 @@
 export function initThreadPool(threads?: number): Promise<void>;
 
 export {
+    init,
+    selfTest,
     Address,
+    CreditsRecord,
     Execution,
     ExecutionResponse,
     Field,
+    Mnemonic,
     OfflineQuery,
     PrivateKey,
     PrivateKeyCiphertext,
@@ -23,4 +27,26 @@ export {
     ViewKey,
     VerifyingKey,
     verifyFunctionExecution,
+    verifyBatchJson,
+    verifyAllStrings,
+    verifySignature,
+    encryptBytes,
+    decryptBytes,
+    verifyFundsAttestation,
+    filterRecordsMinValue,
+    selectRecords,
+    simulateSpend,
+    detectDuplicateNonces,
+    distinctOwners,
+    validateRecords,
+    parseU64Literal,
+    toU64Literal,
+    parsePaymentUri,
+    parseDeepLink,
+    digestHex,
+    memoTag,
+    diagnoseKeyString,
+    validateMnemonic,
+    viewKeyFromPrivateKeyString,
+    sortAddresses,
 } from "./crates/aleo_wasm";
@@ -14,12 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{PrivateKey, Signature, ViewKey};
+use crate::{
+    account::{sanitize_key_string, PrivateKey, Signature, ViewKey},
+    types::digest_hex,
+};
 
-use crate::types::native::AddressNative;
+use crate::types::native::{AddressNative, FromBytes, SignatureNative, ToBytes};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+use js_sys::{BigInt, Object, Reflect};
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
+/// Scheme of payment URIs produced by `Address::to_payment_uri`
+const PAYMENT_URI_SCHEME: &str = "aleo:";
+
+/// Scheme of the deep links registered by wallets and parsed by `parse_deep_link`
+const DEEP_LINK_SCHEME: &str = "aleo://";
+
+/// Prefix of the string representation of an address
+const ADDRESS_PREFIX: &str = "aleo1";
+
 /// Public address of an Aleo account
 #[wasm_bindgen]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -43,12 +58,24 @@ impl Address {
         Self(AddressNative::try_from(**view_key).unwrap())
     }
 
-    /// Create an aleo address object from a string representation of an address
+    /// Create an aleo address object from a string representation of an address. The error
+    /// distinguishes an invalid prefix from a checksum mismatch, which usually means the address
+    /// was mistyped or truncated
     ///
-    /// @param {string} address String representation of an addressm
-    /// @returns {Address} Address
-    pub fn from_string(address: &str) -> Self {
-        Self::from_str(address).unwrap()
+    /// @param {string} address String representation of an address
+    /// @returns {Address | Error} Address
+    pub fn from_string(address: &str) -> Result<Address, String> {
+        let address = sanitize_key_string(address);
+        if !address.to_lowercase().starts_with(ADDRESS_PREFIX) {
+            return Err(format!("Invalid address prefix, expected an address starting with {ADDRESS_PREFIX}"));
+        }
+        match bech32::decode(&address) {
+            Err(bech32::Error::InvalidChecksum) => {
+                Err("Invalid address checksum, the address may be mistyped or truncated".to_string())
+            }
+            Err(error) => Err(format!("Invalid address: {error}")),
+            Ok(_) => Self::from_str(&address).map_err(|error| format!("Invalid address: {error}")),
+        }
     }
 
     /// Get a string representation of an Aleo address object
@@ -60,6 +87,25 @@ impl Address {
         self.0.to_string()
     }
 
+    /// Get the x-coordinate of the address for compact storage as a single field element. The
+    /// x-coordinate alone does not identify a unique point, so reconstructing the address requires
+    /// recovering the y-coordinate and choosing the correct sign
+    ///
+    /// @returns {string} x-coordinate of the address as a field element
+    #[wasm_bindgen(js_name = toXCoordinate)]
+    pub fn to_x_coordinate(&self) -> String {
+        self.0.to_group().to_x_coordinate().to_string()
+    }
+
+    /// Get a deterministic color for the address, i.e. for theming wallet cards. The color is taken
+    /// from the SHA3-256 digest of the address so it is stable per address and evenly distributed
+    ///
+    /// @returns {string} Color of the form `#rrggbb`
+    #[wasm_bindgen(js_name = toThemeColor)]
+    pub fn to_theme_color(&self) -> String {
+        format!("#{}", &digest_hex(self.to_string().as_bytes())[..6])
+    }
+
     /// Verify a signature for a message signed by the address
     ///
     /// @param {Uint8Array} Byte array representing a message signed by the address
@@ -67,13 +113,168 @@ impl Address {
     pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
         signature.verify(self, message)
     }
+
+    /// Verify a time-limited token produced by `PrivateKey.signToken` and return its payload. The
+    /// token is rejected if it was not signed by the address, was tampered with, or has expired
+    ///
+    /// @param {string} token Token of the form `base64(payload).base64(signature).expires_at`
+    /// @returns {Uint8Array | Error} Payload of the token if it is valid
+    #[wasm_bindgen(js_name = verifyToken)]
+    pub fn verify_token(&self, token: &str) -> Result<Vec<u8>, String> {
+        let parts = token.split('.').collect::<Vec<&str>>();
+        if parts.len() != 3 {
+            return Err("The token must contain a payload, signature, and expiration time".to_string());
+        }
+        let payload =
+            URL_SAFE_NO_PAD.decode(parts[0]).map_err(|_| "The token payload is not valid base64".to_string())?;
+        let signature =
+            URL_SAFE_NO_PAD.decode(parts[1]).map_err(|_| "The token signature is not valid base64".to_string())?;
+        let signature =
+            SignatureNative::from_bytes_le(&signature).map_err(|_| "The token signature is invalid".to_string())?;
+        let expires_at = u64::from_str(parts[2]).map_err(|_| "The token expiration time is invalid".to_string())?;
+
+        if !signature.verify_bytes(self, &Signature::token_message(&payload, expires_at)) {
+            return Err("The token signature does not match the address".to_string());
+        }
+        if expires_at <= (js_sys::Date::now() / 1000.0) as u64 {
+            return Err("The token has expired".to_string());
+        }
+        Ok(payload)
+    }
+
+    /// Get a payment URI of the form `aleo:address?amount=X&memo=Y` suitable for encoding in a QR
+    /// code. The amount and memo are only included if provided, and the memo is URL encoded
+    ///
+    /// @param {bigint | undefined} amount Amount of microcredits requested
+    /// @param {string | undefined} memo Memo attached to the payment
+    /// @returns {string} Payment URI for the address
+    #[wasm_bindgen(js_name = toPaymentUri)]
+    pub fn to_payment_uri(&self, amount: Option<u64>, memo: Option<String>) -> String {
+        let mut parameters = vec![];
+        if let Some(amount) = amount {
+            parameters.push(format!("amount={amount}"));
+        }
+        if let Some(memo) = memo {
+            parameters.push(format!("memo={}", String::from(js_sys::encode_uri_component(&memo))));
+        }
+        if parameters.is_empty() {
+            format!("{PAYMENT_URI_SCHEME}{}", self.0)
+        } else {
+            format!("{PAYMENT_URI_SCHEME}{}?{}", self.0, parameters.join("&"))
+        }
+    }
+}
+
+/// Parse a payment URI of the form `aleo:address?amount=X&memo=Y` produced by
+/// `Address.toPaymentUri`
+///
+/// @param {string} uri Payment URI to parse
+/// @returns {Object | Error} Object of the form `{ address, amount, memo }` where the amount and
+/// memo are undefined if they were not included in the URI
+#[wasm_bindgen(js_name = "parsePaymentUri")]
+pub fn parse_payment_uri(uri: &str) -> Result<JsValue, String> {
+    let uri = uri
+        .strip_prefix(PAYMENT_URI_SCHEME)
+        .ok_or_else(|| format!("Payment URI must begin with {PAYMENT_URI_SCHEME}"))?;
+    let (address, query) = uri.split_once('?').unwrap_or((uri, ""));
+    let address =
+        Address::from_string(address).map_err(|error| format!("Payment URI contains an invalid address: {error}"))?;
+
+    let (mut amount, mut memo) = (JsValue::UNDEFINED, JsValue::UNDEFINED);
+    for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+        let (key, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+        match key {
+            "amount" if amount.is_undefined() => {
+                let value = u64::from_str(value).map_err(|_| "Payment URI contains an invalid amount".to_string())?;
+                amount = BigInt::from(value).into();
+            }
+            "memo" if memo.is_undefined() => {
+                let value = js_sys::decode_uri_component(value)
+                    .map_err(|_| "Payment URI contains an invalid memo".to_string())?;
+                memo = value.into();
+            }
+            "amount" | "memo" => return Err(format!("Payment URI contains more than one {key}")),
+            _ => return Err(format!("Payment URI contains an unknown parameter: {key}")),
+        }
+    }
+
+    let payment = Object::new();
+    Reflect::set(&payment, &"address".into(), &address.to_string().into()).map_err(|_| "Failed to set property")?;
+    Reflect::set(&payment, &"amount".into(), &amount).map_err(|_| "Failed to set property")?;
+    Reflect::set(&payment, &"memo".into(), &memo).map_err(|_| "Failed to set property")?;
+    Ok(payment.into())
+}
+
+/// Parse a wallet deep link of the form `aleo://sign?message=X` or `aleo://pay?to=X&amount=Y`.
+/// Parameter values are URL decoded, and every parameter of an action is required
+///
+/// @param {string} url Deep link to parse
+/// @returns {Object | Error} Object of the form `{ action: "sign", message }` or
+/// `{ action: "pay", to, amount }`, or an error if the link is malformed
+#[wasm_bindgen(js_name = "parseDeepLink")]
+pub fn parse_deep_link(url: &str) -> Result<JsValue, String> {
+    let url =
+        url.strip_prefix(DEEP_LINK_SCHEME).ok_or_else(|| format!("Deep link must begin with {DEEP_LINK_SCHEME}"))?;
+    let (action, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let mut parameters = HashMap::new();
+    for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+        let (key, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+        let value = js_sys::decode_uri_component(value)
+            .map_err(|_| format!("Deep link contains an invalid value for {key}"))?;
+        if parameters.insert(key, String::from(value)).is_some() {
+            return Err(format!("Deep link contains more than one {key}"));
+        }
+    }
+    let mut required =
+        |key: &str| parameters.remove(key).ok_or_else(|| format!("Deep link is missing the {key} parameter"));
+
+    let link = Object::new();
+    match action {
+        "sign" => {
+            let message = required("message")?;
+            Reflect::set(&link, &"message".into(), &message.into()).map_err(|_| "Failed to set property")?;
+        }
+        "pay" => {
+            let to = Address::from_string(&required("to")?)
+                .map_err(|error| format!("Deep link contains an invalid address: {error}"))?;
+            let amount =
+                u64::from_str(&required("amount")?).map_err(|_| "Deep link contains an invalid amount".to_string())?;
+            Reflect::set(&link, &"to".into(), &to.to_string().into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&link, &"amount".into(), &BigInt::from(amount).into())
+                .map_err(|_| "Failed to set property")?;
+        }
+        _ => return Err(format!("Deep link contains an unknown action: {action}")),
+    }
+    if let Some(key) = parameters.keys().next() {
+        return Err(format!("Deep link contains an unknown parameter: {key}"));
+    }
+    Reflect::set(&link, &"action".into(), &action.into()).map_err(|_| "Failed to set property")?;
+    Ok(link.into())
+}
+
+/// Sort addresses into a canonical order by comparing the little-endian bytes of each address
+/// lexicographically. This gives a deterministic order for multisig displays and protocols
+///
+/// @param {Address[]} addresses Addresses to sort
+/// @returns {Address[]} Addresses in canonical order
+#[wasm_bindgen(js_name = "sortAddresses")]
+pub fn sort_addresses(mut addresses: Vec<Address>) -> Vec<Address> {
+    addresses.sort_by_cached_key(|address| address.0.to_bytes_le().unwrap());
+    addresses
+}
+
+impl From<AddressNative> for Address {
+    fn from(address: AddressNative) -> Self {
+        Self(address)
+    }
 }
 
 impl FromStr for Address {
     type Err = anyhow::Error;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        Ok(Self(AddressNative::from_str(address)?))
+        Ok(Self(AddressNative::from_str(&sanitize_key_string(address))?))
     }
 }
 
@@ -100,6 +301,9 @@ mod tests {
 
     const ITERATIONS: u64 = 1_000;
 
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const ADDRESS: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+
     #[wasm_bindgen_test]
     pub fn test_from_private_key() {
         for _ in 0..ITERATIONS {
@@ -112,4 +316,213 @@ mod tests {
             assert_eq!(expected, Address::from_view_key(&view_key));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string() {
+        let address = Address::from_string(ADDRESS).unwrap();
+        assert_eq!(address.to_string(), ADDRESS);
+        assert_eq!(address, PrivateKey::from_string(PRIVATE_KEY).unwrap().to_address());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string_invalid() {
+        let prefix_error = "Invalid address prefix, expected an address starting with aleo1";
+        let checksum_error = "Invalid address checksum, the address may be mistyped or truncated";
+
+        // Check a wrong prefix is reported as a prefix error.
+        assert_eq!(Address::from_string(&ADDRESS.replacen("aleo1", "algo1", 1)).unwrap_err(), prefix_error);
+        assert_eq!(Address::from_string(&ADDRESS[5..]).unwrap_err(), prefix_error);
+        assert_eq!(Address::from_string("").unwrap_err(), prefix_error);
+
+        // Check a flipped character and a wrong length are reported as checksum mismatches.
+        assert_eq!(Address::from_string(&ADDRESS.replacen("84vu", "84vv", 1)).unwrap_err(), checksum_error);
+        assert_eq!(Address::from_string(&ADDRESS[..ADDRESS.len() - 1]).unwrap_err(), checksum_error);
+        assert_eq!(Address::from_string(&ADDRESS[..ADDRESS.len() - 10]).unwrap_err(), checksum_error);
+
+        // Check other malformed addresses are still rejected.
+        assert!(Address::from_string(&ADDRESS.replacen('v', "b", 1)).is_err());
+        assert!(Address::from_string("aleo1").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_token() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let payload = b"user_id=1234";
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+
+        // Check a valid token returns its payload.
+        let token = private_key.sign_token(payload, now + 3600);
+        assert_eq!(address.verify_token(&token).unwrap(), payload.to_vec());
+
+        // Check the token is rejected by a different address.
+        let other_address = PrivateKey::new().to_address();
+        assert!(other_address.verify_token(&token).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_expired_token() {
+        let private_key = PrivateKey::new();
+        let token = private_key.sign_token(b"user_id=1234", 1);
+        assert_eq!(private_key.to_address().verify_token(&token).err(), Some("The token has expired".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_tampered_token() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+        let token = private_key.sign_token(b"user_id=1234", now + 3600);
+        let parts = token.split('.').collect::<Vec<&str>>();
+
+        // Check a modified payload is rejected.
+        let tampered_payload = format!("{}.{}.{}", URL_SAFE_NO_PAD.encode(b"user_id=4321"), parts[1], parts[2]);
+        assert!(address.verify_token(&tampered_payload).is_err());
+
+        // Check an extended expiration time is rejected.
+        let tampered_expiration = format!("{}.{}.{}", parts[0], parts[1], now + 7200);
+        assert!(address.verify_token(&tampered_expiration).is_err());
+
+        // Check a malformed token is rejected.
+        assert!(address.verify_token("not_a_token").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_plain_signature_is_not_a_token() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let payload = b"user_id=1234";
+        let expires_at = (js_sys::Date::now() / 1000.0) as u64 + 3600;
+
+        // Check a plain signature over the payload and expiration time is rejected as a token.
+        let mut message = payload.to_vec();
+        message.extend_from_slice(&expires_at.to_le_bytes());
+        let signature = Signature::sign(&private_key, &message);
+        let token = format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature.to_bytes_le().unwrap()),
+            expires_at
+        );
+        assert_eq!(
+            address.verify_token(&token).err(),
+            Some("The token signature does not match the address".to_string())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_x_coordinate() {
+        let address = Address::from_string(ADDRESS).unwrap();
+        let x_coordinate = address.to_x_coordinate();
+        assert!(x_coordinate.ends_with("field"));
+
+        // Check the x-coordinate is stable for the same address.
+        assert_eq!(x_coordinate, address.to_x_coordinate());
+        let private_key = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        assert_eq!(x_coordinate, private_key.to_address().to_x_coordinate());
+
+        // Check a different address has a different x-coordinate.
+        assert_ne!(x_coordinate, PrivateKey::new().to_address().to_x_coordinate());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_theme_color() {
+        let address = Address::from_string(ADDRESS).unwrap();
+        let color = address.to_theme_color();
+        assert_eq!(color.len(), 7);
+        assert!(color.starts_with('#'));
+        assert!(color[1..].chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+
+        // Check the color is stable for the same address.
+        assert_eq!(color, address.to_theme_color());
+        assert_eq!(color, Address::from_string(&address.to_string()).unwrap().to_theme_color());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sort_addresses() {
+        let addresses = (0..5).map(|_| PrivateKey::new().to_address()).collect::<Vec<_>>();
+        let mut reversed = addresses.clone();
+        reversed.reverse();
+
+        let sorted = sort_addresses(addresses.clone());
+        assert_eq!(sorted, sort_addresses(reversed));
+        assert!(sorted.windows(2).all(|pair| pair[0].to_bytes_le().unwrap() <= pair[1].to_bytes_le().unwrap()));
+        assert!(addresses.iter().all(|address| sorted.contains(address)));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_payment_uri_round_trip() {
+        let address = PrivateKey::new().to_address();
+        let uri = address.to_payment_uri(Some(5_000_000), Some("coffee & cake".to_string()));
+        assert_eq!(uri, format!("aleo:{address}?amount=5000000&memo=coffee%20%26%20cake"));
+
+        let payment = parse_payment_uri(&uri).unwrap();
+        let parsed_address = Reflect::get(&payment, &"address".into()).unwrap();
+        let amount = Reflect::get(&payment, &"amount".into()).unwrap();
+        let memo = Reflect::get(&payment, &"memo".into()).unwrap();
+        assert_eq!(parsed_address.as_string().unwrap(), address.to_string());
+        assert_eq!(amount, JsValue::from(BigInt::from(5_000_000u64)));
+        assert_eq!(memo.as_string().unwrap(), "coffee & cake");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_payment_uri_without_optional_fields() {
+        let address = PrivateKey::new().to_address();
+        let uri = address.to_payment_uri(None, None);
+        assert_eq!(uri, format!("aleo:{address}"));
+
+        let payment = parse_payment_uri(&uri).unwrap();
+        let parsed_address = Reflect::get(&payment, &"address".into()).unwrap();
+        assert_eq!(parsed_address.as_string().unwrap(), address.to_string());
+        assert!(Reflect::get(&payment, &"amount".into()).unwrap().is_undefined());
+        assert!(Reflect::get(&payment, &"memo".into()).unwrap().is_undefined());
+
+        // Check a memo without an amount round trips.
+        let uri = address.to_payment_uri(None, Some("rent".to_string()));
+        let memo = Reflect::get(&parse_payment_uri(&uri).unwrap(), &"memo".into()).unwrap();
+        assert_eq!(memo.as_string().unwrap(), "rent");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_invalid_payment_uri() {
+        let address = PrivateKey::new().to_address();
+        assert!(parse_payment_uri(&address.to_string()).is_err());
+        assert!(parse_payment_uri("aleo:not_an_address?amount=1").is_err());
+        let mistyped = ADDRESS.replacen("84vu", "84vv", 1);
+        let checksum_error = Address::from_string(&mistyped).unwrap_err();
+        assert_eq!(
+            parse_payment_uri(&format!("aleo:{mistyped}?amount=1")).unwrap_err(),
+            format!("Payment URI contains an invalid address: {checksum_error}")
+        );
+        assert!(parse_payment_uri(&format!("aleo:{address}?amount=-1")).is_err());
+        assert!(parse_payment_uri(&format!("aleo:{address}?amount=1&amount=2")).is_err());
+        assert!(parse_payment_uri(&format!("aleo:{address}?fee=1")).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_pay_deep_link() {
+        let address = PrivateKey::new().to_address();
+        let link = parse_deep_link(&format!("aleo://pay?to={address}&amount=2500000")).unwrap();
+        assert_eq!(Reflect::get(&link, &"action".into()).unwrap().as_string().unwrap(), "pay");
+        assert_eq!(Reflect::get(&link, &"to".into()).unwrap().as_string().unwrap(), address.to_string());
+        assert_eq!(Reflect::get(&link, &"amount".into()).unwrap(), JsValue::from(BigInt::from(2_500_000u64)));
+
+        let link = parse_deep_link("aleo://sign?message=log%20in%20%F0%9F%94%90").unwrap();
+        assert_eq!(Reflect::get(&link, &"action".into()).unwrap().as_string().unwrap(), "sign");
+        assert_eq!(Reflect::get(&link, &"message".into()).unwrap().as_string().unwrap(), "log in 🔐");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_parse_malformed_deep_link() {
+        let address = PrivateKey::new().to_address();
+        assert!(parse_deep_link(&format!("aleo:pay?to={address}&amount=1")).is_err());
+        assert!(parse_deep_link(&format!("aleo://send?to={address}&amount=1")).is_err());
+        assert!(parse_deep_link(&format!("aleo://pay?to={address}")).is_err());
+        assert!(parse_deep_link("aleo://pay?to=aleo1notanaddress&amount=1").is_err());
+        assert!(parse_deep_link(&format!("aleo://pay?to={address}&amount=-1")).is_err());
+        assert!(parse_deep_link(&format!("aleo://pay?to={address}&amount=1&amount=2")).is_err());
+        assert!(parse_deep_link(&format!("aleo://pay?to={address}&amount=1&memo=x")).is_err());
+        assert!(parse_deep_link("aleo://sign").is_err());
+        assert!(parse_deep_link("aleo://sign?message=%E0%A4%A").is_err());
+    }
 }
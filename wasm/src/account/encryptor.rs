@@ -14,22 +14,150 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::PrivateKey;
 use crate::types::native::{
+    AddressNative,
     CiphertextNative,
     CurrentNetwork,
+    Environment,
     FieldNative,
+    FromBytes,
+    GroupNative,
     IdentifierNative,
     LiteralNative,
     Network,
     PlaintextNative,
+    PrimeField,
     PrivateKeyNative,
+    ScalarNative,
+    ToBytes,
     Uniform,
+    ViewKeyNative,
+    Zero,
 };
 
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::ChaCha20Poly1305;
+use hkdf::Hkdf;
 use once_cell::sync::OnceCell;
-use std::str::FromStr;
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use sha2::Sha256;
+use std::{collections::HashMap, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+use zeroize::Zeroize;
+
+/// Length in bytes of the AEAD nonce included in a message ciphertext
+const MESSAGE_NONCE_LEN: usize = 12;
+
+/// Length in bytes of the ephemeral public key included in a message ciphertext
+const MESSAGE_PUBLIC_KEY_LEN: usize = 32;
+
+/// Default number of PBKDF2-HMAC-SHA256 iterations used to derive a key from a secret, following
+/// the OWASP recommendation for PBKDF2-HMAC-SHA256
+pub(crate) const DEFAULT_KDF_ITERATIONS: u32 = 600_000;
+
+/// Maximum number of PBKDF2 iterations accepted, which bounds the work a crafted ciphertext can
+/// make decryption do
+pub(crate) const MAX_KDF_ITERATIONS: u32 = 10_000_000;
+
+/// Length in bytes of the random salt used to derive a key from a secret
+pub(crate) const KDF_SALT_LEN: usize = 16;
+
+/// Parameters used to derive an encryption key from a secret with PBKDF2-HMAC-SHA256
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct KdfParams {
+    iterations: u32,
+    salt: [u8; KDF_SALT_LEN],
+}
+
+impl KdfParams {
+    /// Create key derivation parameters with the given number of iterations and a random salt
+    pub(crate) fn new(iterations: u32) -> Result<Self, String> {
+        let mut salt = [0u8; KDF_SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Self::from_parts(iterations, salt)
+    }
+
+    /// Create key derivation parameters from a number of iterations and salt read from a ciphertext
+    pub(crate) fn from_parts(iterations: u32, salt: [u8; KDF_SALT_LEN]) -> Result<Self, String> {
+        if iterations == 0 || iterations > MAX_KDF_ITERATIONS {
+            return Err(format!(
+                "The number of iterations must be between 1 and {MAX_KDF_ITERATIONS}, found {iterations}"
+            ));
+        }
+        Ok(Self { iterations, salt })
+    }
+
+    /// Get the number of PBKDF2 iterations
+    pub(crate) fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Get the salt
+    pub(crate) fn salt(&self) -> &[u8; KDF_SALT_LEN] {
+        &self.salt
+    }
+}
+
+/// AEAD ciphers supported for message encryption, identified by the first byte of a message ciphertext
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MessageCipher {
+    Aes256Gcm = 1,
+    ChaCha20Poly1305 = 2,
+}
+
+impl MessageCipher {
+    // Get a cipher from its name
+    fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "aes-256-gcm" => Ok(Self::Aes256Gcm),
+            "chacha20-poly1305" => Ok(Self::ChaCha20Poly1305),
+            _ => Err(format!("Unsupported cipher {name}, expected aes-256-gcm or chacha20-poly1305")),
+        }
+    }
+
+    // Get a cipher from its identifier in a message ciphertext header
+    fn from_id(id: u8) -> Result<Self, String> {
+        match id {
+            1 => Ok(Self::Aes256Gcm),
+            2 => Ok(Self::ChaCha20Poly1305),
+            _ => Err(format!("Unknown cipher identifier {id} in message ciphertext")),
+        }
+    }
+
+    // Encrypt and authenticate a plaintext
+    fn seal(self, key: &[u8], nonce: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        let ciphertext = match self {
+            Self::Aes256Gcm => {
+                Aes256Gcm::new_from_slice(key).map_err(|e| e.to_string())?.encrypt(nonce.into(), plaintext)
+            }
+            Self::ChaCha20Poly1305 => {
+                ChaCha20Poly1305::new_from_slice(key).map_err(|e| e.to_string())?.encrypt(nonce.into(), plaintext)
+            }
+        };
+        ciphertext.map_err(|_| "Message encryption failed".to_string())
+    }
 
-/// Tool for encrypting and decrypting Aleo key material into ciphertext
+    // Authenticate and decrypt a ciphertext
+    fn open(self, key: &[u8], nonce: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        let plaintext = match self {
+            Self::Aes256Gcm => {
+                Aes256Gcm::new_from_slice(key).map_err(|e| e.to_string())?.decrypt(nonce.into(), ciphertext)
+            }
+            Self::ChaCha20Poly1305 => {
+                ChaCha20Poly1305::new_from_slice(key).map_err(|e| e.to_string())?.decrypt(nonce.into(), ciphertext)
+            }
+        };
+        plaintext.map_err(|_| "Message decryption failed".to_string())
+    }
+}
+
+/// Tool for encrypting and decrypting Aleo key material and messages into ciphertext
 pub struct Encryptor;
 
 impl Encryptor {
@@ -38,7 +166,18 @@ impl Encryptor {
         private_key: &PrivateKeyNative,
         secret: &str,
     ) -> Result<CiphertextNative, String> {
-        Self::encrypt_field(&private_key.seed(), secret, "private_key")
+        Self::encrypt_private_key_with_params(private_key, secret, None)
+    }
+
+    /// Encrypt a private key into ciphertext using a secret stretched with PBKDF2 using the given
+    /// parameters. Without parameters the secret is used directly, as by
+    /// `encrypt_private_key_with_secret`
+    pub(crate) fn encrypt_private_key_with_params(
+        private_key: &PrivateKeyNative,
+        secret: &str,
+        params: Option<&KdfParams>,
+    ) -> Result<CiphertextNative, String> {
+        Self::encrypt_field_with_key(&private_key.seed(), Self::secret_field(secret, params)?, "private_key")
     }
 
     /// Decrypt a private key from ciphertext using a secret
@@ -46,15 +185,217 @@ impl Encryptor {
         ciphertext: &CiphertextNative,
         secret: &str,
     ) -> Result<PrivateKeyNative, String> {
-        let seed = Self::decrypt_field(ciphertext, secret, "private_key")?;
+        Self::decrypt_private_key_with_params(ciphertext, secret, None)
+    }
+
+    /// Decrypt a private key from ciphertext using a secret stretched with the parameters the
+    /// private key was encrypted with
+    pub(crate) fn decrypt_private_key_with_params(
+        ciphertext: &CiphertextNative,
+        secret: &str,
+        params: Option<&KdfParams>,
+    ) -> Result<PrivateKeyNative, String> {
+        let seed = Self::decrypt_field_with_key(ciphertext, Self::secret_field(secret, params)?, "private_key")?;
+        PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
+    }
+
+    /// Re-encrypt a private key ciphertext under a new secret, stretching the new secret with the
+    /// new parameters. The private key seed is only held as a field element for the duration of
+    /// the call and is overwritten before returning
+    pub(crate) fn reencrypt_private_key_with_secret(
+        ciphertext: &CiphertextNative,
+        old_secret: &str,
+        new_secret: &str,
+        old_params: Option<&KdfParams>,
+        new_params: Option<&KdfParams>,
+    ) -> Result<CiphertextNative, String> {
+        let mut seed =
+            Self::decrypt_field_with_key(ciphertext, Self::secret_field(old_secret, old_params)?, "private_key")?;
+        // Check the seed recovered with the old secret is a valid private key
+        let reencrypted = PrivateKeyNative::try_from(seed).map_err(|e| e.to_string()).and_then(|_| {
+            Self::encrypt_field_with_key(&seed, Self::secret_field(new_secret, new_params)?, "private_key")
+        });
+        seed = FieldNative::zero();
+        core::hint::black_box(&seed);
+        reencrypted
+    }
+
+    /// Decrypt a batch of private keys encrypted under the same secret, given with the key
+    /// derivation parameters each was encrypted with. The key material is derived from the secret
+    /// once for each distinct set of parameters and reused
+    pub(crate) fn decrypt_private_keys_with_secret(
+        ciphertexts: &[(CiphertextNative, Option<KdfParams>)],
+        secret: &str,
+    ) -> Result<Vec<PrivateKeyNative>, String> {
+        let mut keys = HashMap::new();
+        ciphertexts
+            .iter()
+            .enumerate()
+            .map(|(index, (ciphertext, params))| {
+                let key = match keys.get(params) {
+                    Some(key) => *key,
+                    None => {
+                        let key = Self::secret_field(secret, params.as_ref())?;
+                        keys.insert(*params, key);
+                        key
+                    }
+                };
+                Self::decrypt_field_with_key(ciphertext, key, "private_key")
+                    .and_then(|seed| PrivateKeyNative::try_from(seed).map_err(|e| e.to_string()))
+                    .map_err(|_| format!("Decryption failed for ciphertext {index}"))
+            })
+            .collect()
+    }
+
+    /// Encrypt a private key into ciphertext which can only be decrypted by the owner of an address.
+    /// The ciphertext is returned along with the nonce needed by the recipient to decrypt it
+    pub(crate) fn encrypt_private_key_to_address(
+        private_key: &PrivateKeyNative,
+        recipient: &AddressNative,
+    ) -> Result<(GroupNative, CiphertextNative), String> {
+        // Derive a shared key from an ephemeral randomizer and the recipient address.
+        let randomizer = ScalarNative::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let key = (*recipient.to_group() * randomizer).to_x_coordinate();
+        Ok((nonce, Self::encrypt_field_with_key(&private_key.seed(), key, "private_key_escrow")?))
+    }
+
+    /// Decrypt a private key encrypted to an address using the view key of the recipient
+    pub(crate) fn decrypt_private_key_from_escrow(
+        nonce: &GroupNative,
+        ciphertext: &CiphertextNative,
+        view_key: &ViewKeyNative,
+    ) -> Result<PrivateKeyNative, String> {
+        // Recover the shared key from the nonce and the recipient view key.
+        let key = (*nonce * **view_key).to_x_coordinate();
+        let seed = Self::decrypt_field_with_key(ciphertext, key, "private_key_escrow")?;
         PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
     }
 
-    // Encrypted a field element into a ciphertext representation
-    fn encrypt_field(field: &FieldNative, secret: &str, domain: &str) -> Result<CiphertextNative, String> {
-        // Derive the domain separators and the secret.
+    /// Encrypt a message so that it can only be decrypted by the owner of an address, using the
+    /// named AEAD cipher (`aes-256-gcm` or `chacha20-poly1305`). The ciphertext is of the form
+    /// `cipher_id || ephemeral_public_key || nonce || ciphertext`, so decryption does not need to
+    /// be told which cipher was used
+    pub fn encrypt_message_with_cipher(
+        recipient: &AddressNative,
+        plaintext: &[u8],
+        cipher: &str,
+    ) -> Result<Vec<u8>, String> {
+        let cipher = MessageCipher::from_name(cipher)?;
+
+        // Derive a shared key from an ephemeral keypair and the recipient address.
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        let (ephemeral_key, public_key) = PrivateKey::new_ephemeral(&seed);
+        let key = Self::message_key(*recipient.to_group() * **ephemeral_key.to_view_key())?;
+
+        let mut nonce = [0u8; MESSAGE_NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut ciphertext = vec![cipher as u8];
+        ciphertext.extend_from_slice(&public_key.to_group().to_bytes_le().map_err(|e| e.to_string())?);
+        ciphertext.extend_from_slice(&nonce);
+        ciphertext.extend_from_slice(&cipher.seal(&key, &nonce, plaintext)?);
+        Ok(ciphertext)
+    }
+
+    /// Decrypt a message encrypted to an address using the view key of the recipient. The cipher
+    /// is determined from the header of the ciphertext
+    pub fn decrypt_message(view_key: &ViewKeyNative, ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        let header_len = 1 + MESSAGE_PUBLIC_KEY_LEN + MESSAGE_NONCE_LEN;
+        if ciphertext.len() < header_len {
+            return Err("The message ciphertext is too short".to_string());
+        }
+        let cipher = MessageCipher::from_id(ciphertext[0])?;
+        let public_key = GroupNative::from_bytes_le(&ciphertext[1..1 + MESSAGE_PUBLIC_KEY_LEN])
+            .map_err(|_| "The message ciphertext contains an invalid public key".to_string())?;
+        let nonce = &ciphertext[1 + MESSAGE_PUBLIC_KEY_LEN..header_len];
+
+        // Recover the shared key from the ephemeral public key and the recipient view key.
+        let key = Self::message_key(public_key * **view_key)?;
+        cipher.open(&key, nonce, &ciphertext[header_len..])
+    }
+
+    /// Encrypt bytes with a secret using AES-256-GCM. The key is derived from the secret with
+    /// PBKDF2-HMAC-SHA256 using the given number of iterations and a random salt, and the
+    /// ciphertext is of the form `cipher_id || iterations || salt || nonce || ciphertext`, with the
+    /// number of iterations as a little endian u32
+    pub fn encrypt_bytes_with_secret(plaintext: &[u8], secret: &str, iterations: u32) -> Result<Vec<u8>, String> {
+        let cipher = MessageCipher::Aes256Gcm;
+        let params = KdfParams::new(iterations)?;
+        let mut nonce = [0u8; MESSAGE_NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let mut key = Self::stretch_secret(secret, &params);
+
+        let mut ciphertext = vec![cipher as u8];
+        ciphertext.extend_from_slice(&params.iterations().to_le_bytes());
+        ciphertext.extend_from_slice(params.salt());
+        ciphertext.extend_from_slice(&nonce);
+        let sealed = cipher.seal(&key, &nonce, plaintext);
+        key.zeroize();
+        ciphertext.extend_from_slice(&sealed?);
+        Ok(ciphertext)
+    }
+
+    /// Decrypt bytes encrypted with a secret. The key is derived with the number of iterations
+    /// and salt recorded in the ciphertext. Decrypting with the wrong secret fails authentication
+    /// and returns an error
+    pub fn decrypt_bytes_with_secret(ciphertext: &[u8], secret: &str) -> Result<Vec<u8>, String> {
+        let salt_start = 1 + 4;
+        let nonce_start = salt_start + KDF_SALT_LEN;
+        let header_len = nonce_start + MESSAGE_NONCE_LEN;
+        if ciphertext.len() < header_len {
+            return Err("The ciphertext is too short".to_string());
+        }
+        let cipher = MessageCipher::from_id(ciphertext[0])?;
+        let iterations = u32::from_le_bytes(ciphertext[1..salt_start].try_into().unwrap());
+        let params = KdfParams::from_parts(iterations, ciphertext[salt_start..nonce_start].try_into().unwrap())?;
+        let mut key = Self::stretch_secret(secret, &params);
+        let plaintext = cipher.open(&key, &ciphertext[nonce_start..header_len], &ciphertext[header_len..]);
+        key.zeroize();
+        plaintext.map_err(|_| "Decryption failed - the secret is incorrect or the ciphertext is corrupt".to_string())
+    }
+
+    // Derive a symmetric message key from a shared point
+    fn message_key(shared_point: GroupNative) -> Result<[u8; 32], String> {
+        let shared_secret = shared_point.to_x_coordinate().to_bytes_le().map_err(|e| e.to_string())?;
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(None, &shared_secret)
+            .expand(b"AleoMessageEncryption", &mut key)
+            .map_err(|_| "Failed to derive the message key".to_string())?;
+        Ok(key)
+    }
+
+    // Stretch a secret into a key with PBKDF2-HMAC-SHA256. This runs synchronously on the calling
+    // thread, so its cost grows linearly with the number of iterations
+    fn stretch_secret(secret: &str, params: &KdfParams) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        pbkdf2_hmac::<Sha256>(secret.as_bytes(), params.salt(), params.iterations(), &mut key);
+        key
+    }
+
+    // Derive the field element key for a secret. Ciphertexts without key derivation parameters
+    // predate key stretching and use the domain separator of the secret directly
+    fn secret_field(secret: &str, params: Option<&KdfParams>) -> Result<FieldNative, String> {
+        match params {
+            Some(params) => {
+                let mut key = Self::stretch_secret(secret, params);
+                let field = FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&key));
+                key.zeroize();
+                Ok(field)
+            }
+            None => Ok(FieldNative::new_domain_separator(secret)),
+        }
+    }
+
+    // Encrypt a field element into a ciphertext representation using a field element as the key
+    fn encrypt_field_with_key(
+        field: &FieldNative,
+        secret: FieldNative,
+        domain: &str,
+    ) -> Result<CiphertextNative, String> {
+        // Derive the domain separator.
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
 
         // Generate a nonce
         let mut rng = rand::thread_rng();
@@ -79,10 +420,13 @@ impl Encryptor {
         plaintext.encrypt_symmetric(secret).map_err(|e| e.to_string())
     }
 
-    // Recover a field element encrypted within ciphertext
-    fn decrypt_field(ciphertext: &CiphertextNative, secret: &str, domain: &str) -> Result<FieldNative, String> {
+    // Recover a field element encrypted within ciphertext using a field element as the key
+    fn decrypt_field_with_key(
+        ciphertext: &CiphertextNative,
+        secret: FieldNative,
+        domain: &str,
+    ) -> Result<FieldNative, String> {
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
         let decrypted = ciphertext.decrypt_symmetric(secret).map_err(|e| e.to_string())?;
         let recovered_key = Self::extract_value(&decrypted, "key")?;
         let recovered_nonce = Self::extract_value(&decrypted, "nonce")?;
@@ -105,6 +449,36 @@ impl Encryptor {
     }
 }
 
+/// Encrypt a small blob of bytes, such as a record note, with a secret. This uses the AES-256-GCM
+/// cipher used for message encryption, so decrypting with the wrong secret is detected. The secret
+/// is stretched with PBKDF2-HMAC-SHA256, which runs synchronously and blocks the calling thread for
+/// time proportional to the number of iterations, both here and in `decryptBytes`. With the
+/// default of 600,000 iterations this can take a second or more on slower devices, so it should
+/// be called from a web worker rather than the main thread
+///
+/// @param {Uint8Array} plaintext Bytes to encrypt
+/// @param {string} secret Secret used to encrypt the bytes
+/// @param {number | undefined} iterations Number of PBKDF2 iterations, between 1 and 10,000,000,
+/// defaulting to 600,000
+/// @returns {string | Error} Base64 encoding of the ciphertext
+#[wasm_bindgen(js_name = "encryptBytes")]
+pub fn encrypt_bytes(plaintext: &[u8], secret: &str, iterations: Option<u32>) -> Result<String, String> {
+    Encryptor::encrypt_bytes_with_secret(plaintext, secret, iterations.unwrap_or(DEFAULT_KDF_ITERATIONS))
+        .map(|ciphertext| STANDARD.encode(ciphertext))
+}
+
+/// Decrypt bytes encrypted with `encryptBytes`
+///
+/// @param {string} ciphertext Base64 encoding of the ciphertext
+/// @param {string} secret Secret used to encrypt the bytes
+/// @returns {Uint8Array | Error} Decrypted bytes, or an error if the secret is incorrect or the
+/// ciphertext is corrupt
+#[wasm_bindgen(js_name = "decryptBytes")]
+pub fn decrypt_bytes(ciphertext: &str, secret: &str) -> Result<Vec<u8>, String> {
+    let ciphertext = STANDARD.decode(ciphertext).map_err(|_| "The ciphertext is not valid base64".to_string())?;
+    Encryptor::decrypt_bytes_with_secret(&ciphertext, secret)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +486,86 @@ mod tests {
     use snarkvm_console::prelude::TestRng;
     use wasm_bindgen_test::*;
 
+    #[wasm_bindgen_test]
+    fn test_encryptor_message_round_trip() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let view_key = ViewKeyNative::try_from(private_key).unwrap();
+        let address = AddressNative::try_from(private_key).unwrap();
+        let message = b"meet at the usual place";
+
+        for (cipher, id) in [("aes-256-gcm", 1u8), ("chacha20-poly1305", 2u8)] {
+            let ciphertext = Encryptor::encrypt_message_with_cipher(&address, message, cipher).unwrap();
+            assert_eq!(ciphertext[0], id);
+            assert_eq!(Encryptor::decrypt_message(&view_key, &ciphertext).unwrap(), message);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_bytes_round_trip() {
+        let note = "rent for march 🏠".as_bytes();
+        let ciphertext = encrypt_bytes(note, "mypassword", Some(1000)).unwrap();
+        assert_eq!(decrypt_bytes(&ciphertext, "mypassword").unwrap(), note);
+
+        // Check the number of iterations is recorded in the header.
+        let decoded = STANDARD.decode(&ciphertext).unwrap();
+        assert_eq!(decoded[1..5], 1000u32.to_le_bytes());
+
+        // Check the same plaintext encrypts differently each time and empty blobs round trip.
+        assert_ne!(encrypt_bytes(note, "mypassword", Some(1000)).unwrap(), ciphertext);
+        assert!(decrypt_bytes(&encrypt_bytes(&[], "mypassword", Some(1)).unwrap(), "mypassword").unwrap().is_empty());
+
+        // Check zero and excessive iteration counts are rejected when encrypting and decrypting.
+        assert!(encrypt_bytes(note, "mypassword", Some(0)).is_err());
+        assert!(encrypt_bytes(note, "mypassword", Some(MAX_KDF_ITERATIONS + 1)).is_err());
+        let mut excessive = decoded;
+        excessive[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(decrypt_bytes(&STANDARD.encode(&excessive), "mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_bytes_wrong_secret() {
+        let ciphertext = encrypt_bytes(b"note", "mypassword", Some(1000)).unwrap();
+        assert_eq!(
+            decrypt_bytes(&ciphertext, "wrongpassword").unwrap_err(),
+            "Decryption failed - the secret is incorrect or the ciphertext is corrupt"
+        );
+
+        // Check corrupt and malformed ciphertexts are errors rather than panics.
+        let mut tampered = STANDARD.decode(&ciphertext).unwrap();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(decrypt_bytes(&STANDARD.encode(&tampered), "mypassword").is_err());
+        assert!(decrypt_bytes(&STANDARD.encode(&tampered[..10]), "mypassword").is_err());
+        assert!(decrypt_bytes("not base64!", "mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_message_failures() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let address = AddressNative::try_from(private_key).unwrap();
+        let wrong_view_key = ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap();
+        let view_key = ViewKeyNative::try_from(private_key).unwrap();
+
+        // Check unsupported ciphers are rejected.
+        assert!(Encryptor::encrypt_message_with_cipher(&address, b"message", "des").is_err());
+
+        // Check the wrong view key cannot decrypt the message.
+        let ciphertext = Encryptor::encrypt_message_with_cipher(&address, b"message", "aes-256-gcm").unwrap();
+        assert!(Encryptor::decrypt_message(&wrong_view_key, &ciphertext).is_err());
+
+        // Check switching the cipher in the header fails authentication.
+        let mut tampered = ciphertext.clone();
+        tampered[0] = MessageCipher::ChaCha20Poly1305 as u8;
+        assert!(Encryptor::decrypt_message(&view_key, &tampered).is_err());
+
+        // Check unknown ciphers and truncated ciphertexts are rejected.
+        tampered[0] = 0;
+        assert!(Encryptor::decrypt_message(&view_key, &tampered).is_err());
+        assert!(Encryptor::decrypt_message(&view_key, &ciphertext[..20]).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_encryptor_encrypt_and_decrypt() {
         let mut rng = TestRng::default();
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt;
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+/// Errors returned by account operations. In JS the error is thrown as an `Error` whose `code`
+/// property is stable across releases, while the message is kept for backwards compatibility
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountError {
+    /// A private key could not be decrypted
+    Decryption,
+    /// A private key could not be encrypted
+    EncryptionFailed,
+    /// A string was not a valid private key
+    InvalidPrivateKey,
+    /// A seed was not 32 bytes, with the length of the seed provided
+    InvalidSeedLength(usize),
+}
+
+impl AccountError {
+    /// Get the stable error code surfaced to JS for the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Decryption => "DECRYPTION_FAILED",
+            Self::EncryptionFailed => "ENCRYPTION_FAILED",
+            Self::InvalidPrivateKey => "INVALID_PRIVATE_KEY",
+            Self::InvalidSeedLength(_) => "INVALID_SEED_LENGTH",
+        }
+    }
+}
+
+impl fmt::Display for AccountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Decryption => write!(f, "Decryption failed"),
+            Self::EncryptionFailed => write!(f, "Encryption failed"),
+            Self::InvalidPrivateKey => write!(f, "Invalid private key"),
+            Self::InvalidSeedLength(length) => write!(f, "Invalid seed length, expected 32 bytes but found {length}"),
+        }
+    }
+}
+
+impl From<AccountError> for String {
+    fn from(error: AccountError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<AccountError> for JsValue {
+    fn from(error: AccountError) -> Self {
+        js_error_with_code(&error.to_string(), error.code())
+    }
+}
+
+/// Create a JS `Error` with a message and a `code` property, which is how errors with stable codes
+/// are surfaced to JS
+pub(crate) fn js_error_with_code(message: &str, code: &str) -> JsValue {
+    let js_error = js_sys::Error::new(message);
+    // Setting a property on a freshly created error object cannot fail
+    let _ = Reflect::set(&js_error, &"code".into(), &code.into());
+    js_error.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::PrivateKey;
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_error_codes() {
+        let error = PrivateKey::from_string("APrivateKey1invalid").unwrap_err();
+        assert_eq!(error, AccountError::InvalidPrivateKey);
+        assert_eq!(error.code(), "INVALID_PRIVATE_KEY");
+        assert_eq!(error.to_string(), "Invalid private key");
+
+        let error = PrivateKey::from_seed(&[0u8; 31]).unwrap_err();
+        assert_eq!(error, AccountError::InvalidSeedLength(31));
+        assert_eq!(error.code(), "INVALID_SEED_LENGTH");
+
+        let ciphertext = PrivateKey::new().to_ciphertext("mypassword").unwrap();
+        let error = PrivateKey::from_private_key_ciphertext(&ciphertext, "badpassword").unwrap_err();
+        assert_eq!(error, AccountError::Decryption);
+        assert_eq!(error.code(), "DECRYPTION_FAILED");
+        assert_eq!(error.to_string(), "Decryption failed");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_error_to_js() {
+        for error in [
+            AccountError::Decryption,
+            AccountError::EncryptionFailed,
+            AccountError::InvalidPrivateKey,
+            AccountError::InvalidSeedLength(0),
+        ] {
+            let js_error = JsValue::from(error.clone());
+            assert!(js_error.is_instance_of::<js_sys::Error>());
+            assert_eq!(Reflect::get(&js_error, &"code".into()).unwrap(), error.code());
+            assert_eq!(Reflect::get(&js_error, &"message".into()).unwrap(), error.to_string());
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use wasm_bindgen::prelude::*;
+
+/// Invisible characters commonly picked up when keys are copied from documents or chat messages
+const INVISIBLE_CHARACTERS: [(char, &str); 5] = [
+    ('\u{feff}', "byte order mark (U+FEFF)"),
+    ('\u{200b}', "zero-width space (U+200B)"),
+    ('\u{200c}', "zero-width non-joiner (U+200C)"),
+    ('\u{200d}', "zero-width joiner (U+200D)"),
+    ('\u{2060}', "word joiner (U+2060)"),
+];
+
+/// Remove invisible characters and surrounding whitespace from a key string before it is parsed
+pub(crate) fn sanitize_key_string(key: &str) -> String {
+    key.chars().filter(|c| !is_invisible(c)).collect::<String>().trim().to_string()
+}
+
+// Check if a character is one of the invisible characters stripped from key strings
+fn is_invisible(c: &char) -> bool {
+    INVISIBLE_CHARACTERS.iter().any(|(invisible, _)| invisible == c)
+}
+
+/// Report which characters are stripped from a key string before it is parsed. Keys copied from
+/// PDFs or chat messages often contain invisible characters which otherwise cause parsing to fail
+///
+/// @param {string} key Private key, view key, or address string
+/// @returns {string} Description of the characters which are stripped from the key string
+#[wasm_bindgen(js_name = "diagnoseKeyString")]
+pub fn diagnose_key_string(key: &str) -> String {
+    let mut stripped = INVISIBLE_CHARACTERS
+        .iter()
+        .filter_map(|(invisible, name)| match key.chars().filter(|c| c == invisible).count() {
+            0 => None,
+            count => Some(format!("{name} x{count}")),
+        })
+        .collect::<Vec<String>>();
+    let visible = key.chars().filter(|c| !is_invisible(c)).collect::<String>();
+    if visible.trim() != visible {
+        stripped.push("surrounding whitespace".to_string());
+    }
+    if stripped.is_empty() {
+        "No invisible characters or surrounding whitespace were found".to_string()
+    } else {
+        format!("Stripped {}", stripped.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::{Address, PrivateKey, ViewKey};
+
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const VIEW_KEY: &str = "AViewKey1cxguxtKkjYnT9XDza9yTvVMxt6Ckb1Pv4ck1hppMzmCB";
+    const ADDRESS: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+
+    #[wasm_bindgen_test]
+    fn test_keys_with_bom_parse() {
+        let private_key = PrivateKey::from_string(&format!("\u{feff}{PRIVATE_KEY}")).unwrap();
+        assert_eq!(private_key.to_string(), PRIVATE_KEY);
+        let view_key = ViewKey::from_string(&format!("\u{feff}{VIEW_KEY}\n")).unwrap();
+        assert_eq!(view_key.to_string(), VIEW_KEY);
+        let address = Address::from_string(&format!(" {ADDRESS}\u{200b}")).unwrap();
+        assert_eq!(address.to_string(), ADDRESS);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_diagnose_key_string() {
+        assert_eq!(diagnose_key_string(&format!("\u{feff}{PRIVATE_KEY}")), "Stripped byte order mark (U+FEFF) x1");
+        assert_eq!(
+            diagnose_key_string(&format!(" \u{200b}{ADDRESS}\u{200b}\n")),
+            "Stripped zero-width space (U+200B) x2, surrounding whitespace"
+        );
+        assert_eq!(diagnose_key_string(VIEW_KEY), "No invisible characters or surrounding whitespace were found");
+    }
+}
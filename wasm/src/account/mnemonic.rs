@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::PrivateKey;
+
+use bip39::{Language, Mnemonic as MnemonicNative};
+use core::{fmt, str::FromStr};
+use hmac::{Hmac, Mac};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use sha2::Sha512;
+use wasm_bindgen::prelude::*;
+
+/// Key used to derive account seeds from a BIP39 mnemonic seed
+const ACCOUNT_SEED_KEY: &[u8] = b"Aleo seed";
+
+/// BIP39 mnemonic phrase from which Aleo private keys can be deterministically recovered
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mnemonic(MnemonicNative);
+
+#[wasm_bindgen]
+impl Mnemonic {
+    /// Generate a new English mnemonic using a cryptographically secure random number generator
+    ///
+    /// @param {number} word_count Number of words in the mnemonic, either 12 or 24
+    /// @returns {Mnemonic | Error} New mnemonic
+    pub fn generate(word_count: u32) -> Result<Mnemonic, String> {
+        let mut entropy = match word_count {
+            12 => vec![0u8; 16],
+            24 => vec![0u8; 32],
+            _ => return Err(format!("Mnemonics must contain 12 or 24 words, found {word_count}")),
+        };
+        StdRng::from_entropy().fill_bytes(&mut entropy);
+        MnemonicNative::from_entropy_in(Language::English, &entropy).map(Self).map_err(|error| error.to_string())
+    }
+
+    /// Recover a mnemonic from an English phrase
+    ///
+    /// @param {string} phrase Space separated words of the mnemonic
+    /// @returns {Mnemonic | Error} Mnemonic, or an error if the phrase is not a valid 12 or 24 word mnemonic
+    #[wasm_bindgen(js_name = fromPhrase)]
+    pub fn from_phrase(phrase: &str) -> Result<Mnemonic, String> {
+        Self::from_str(phrase).map_err(|error| format!("Invalid mnemonic: {error}"))
+    }
+
+    /// Get the phrase of the mnemonic. This function should be used very carefully as the phrase
+    /// can be used to recover every private key derived from the mnemonic
+    ///
+    /// @returns {string} Space separated words of the mnemonic
+    #[wasm_bindgen(js_name = toPhrase)]
+    pub fn to_phrase(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the number of words in the mnemonic
+    ///
+    /// @returns {number} Number of words in the mnemonic
+    #[wasm_bindgen(js_name = wordCount)]
+    pub fn word_count(&self) -> usize {
+        self.0.word_count()
+    }
+
+    /// Deterministically derive the private key of an account from the mnemonic. The BIP39 seed of
+    /// the mnemonic is combined with the account index using HMAC-SHA512, and the first 32 bytes of
+    /// the result are used as the seed of the private key as in `PrivateKey.from_seed_unchecked`
+    ///
+    /// @param {number} account_index Index of the account to derive
+    /// @returns {PrivateKey} Private key of the account
+    #[wasm_bindgen(js_name = toPrivateKey)]
+    pub fn to_private_key(&self, account_index: u32) -> PrivateKey {
+        let mut mac = Hmac::<Sha512>::new_from_slice(ACCOUNT_SEED_KEY).unwrap();
+        mac.update(&self.0.to_seed_normalized(""));
+        mac.update(&account_index.to_be_bytes());
+        PrivateKey::from_seed_unchecked(&mac.finalize().into_bytes()[..32])
+    }
+}
+
+/// Check that a phrase is a valid 12 or 24 word English mnemonic without deriving any keys, such as
+/// to validate a recovery phrase as it is typed
+///
+/// @param {string} phrase Space separated words of the mnemonic
+/// @returns {void | Error} Nothing if the phrase is valid, otherwise an error describing whether the
+/// phrase has the wrong number of words, contains an unknown word, or has an invalid checksum
+#[wasm_bindgen(js_name = "validateMnemonic")]
+pub fn validate_mnemonic(phrase: &str) -> Result<(), String> {
+    let phrase = normalize_phrase(phrase);
+    match Mnemonic::from_str(&phrase) {
+        Ok(_) => Ok(()),
+        Err(bip39::Error::BadWordCount(count)) => Err(format!("Mnemonics must contain 12 or 24 words, found {count}")),
+        Err(bip39::Error::UnknownWord(index)) => {
+            let word = phrase.split(' ').nth(index).unwrap_or_default();
+            Err(format!("Unknown word \"{word}\" at position {}", index + 1))
+        }
+        Err(bip39::Error::InvalidChecksum) => {
+            Err("Invalid mnemonic checksum, check the words are correct and in order".to_string())
+        }
+        Err(error) => Err(format!("Invalid mnemonic: {error}")),
+    }
+}
+
+// Normalize the whitespace and case of a mnemonic phrase
+fn normalize_phrase(phrase: &str) -> String {
+    phrase.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+impl FromStr for Mnemonic {
+    type Err = bip39::Error;
+
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        let phrase = normalize_phrase(phrase);
+        let mnemonic = MnemonicNative::parse_in_normalized(Language::English, &phrase)?;
+        if !matches!(mnemonic.word_count(), 12 | 24) {
+            return Err(bip39::Error::BadWordCount(mnemonic.word_count()));
+        }
+        Ok(Self(mnemonic))
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const PRIVATE_KEY_0: &str = "APrivateKey1zkpEaRVWLVVc8jd1568FztCtGsf56TUNoMmYz2pGyQk45oC";
+    const PRIVATE_KEY_1: &str = "APrivateKey1zkpAFU4RYKGRTX1Vr3VWXrzw9Tk5SbA36PHbwbJaPBKoq8h";
+
+    #[wasm_bindgen_test]
+    fn test_to_private_key() {
+        let mnemonic = Mnemonic::from_phrase(PHRASE).unwrap();
+
+        // Check the derived private keys are stable.
+        let private_key = mnemonic.to_private_key(0);
+        assert_eq!(private_key.to_string(), PRIVATE_KEY_0);
+        assert_eq!(mnemonic.to_private_key(1).to_string(), PRIVATE_KEY_1);
+
+        // Check the derived address matches the address of the expected private key.
+        let expected = PrivateKey::from_string(PRIVATE_KEY_0).unwrap();
+        assert_eq!(private_key.to_address(), expected.to_address());
+        assert_eq!(private_key.to_address(), mnemonic.to_private_key(0).to_address());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_generate_round_trip() {
+        for word_count in [12, 24] {
+            let mnemonic = Mnemonic::generate(word_count).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count as usize);
+            assert_eq!(mnemonic.to_phrase().split(' ').count(), word_count as usize);
+
+            let recovered = Mnemonic::from_phrase(&mnemonic.to_phrase()).unwrap();
+            assert_eq!(recovered, mnemonic);
+            assert_eq!(recovered.to_private_key(0), mnemonic.to_private_key(0));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_mnemonic() {
+        assert!(validate_mnemonic(PHRASE).is_ok());
+        assert!(validate_mnemonic(&Mnemonic::generate(24).unwrap().to_phrase()).is_ok());
+
+        // Check a wrong number of words is reported.
+        let short = PHRASE.rsplit_once(' ').unwrap().0;
+        assert_eq!(validate_mnemonic(short).unwrap_err(), "Mnemonics must contain 12 or 24 words, found 11");
+        let mnemonic = MnemonicNative::from_entropy_in(Language::English, &[0u8; 20]).unwrap();
+        assert_eq!(
+            validate_mnemonic(&mnemonic.to_string()).unwrap_err(),
+            "Mnemonics must contain 12 or 24 words, found 15"
+        );
+
+        // Check an unknown word is reported with its position.
+        assert_eq!(
+            validate_mnemonic(&PHRASE.replacen("abandon", "aleo", 1)).unwrap_err(),
+            "Unknown word \"aleo\" at position 1"
+        );
+
+        // Check a bad checksum is reported.
+        assert_eq!(
+            validate_mnemonic(&PHRASE.replace("about", "abandon")).unwrap_err(),
+            "Invalid mnemonic checksum, check the words are correct and in order"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid() {
+        assert!(Mnemonic::generate(15).is_err());
+        assert!(Mnemonic::from_phrase("").is_err());
+        assert!(Mnemonic::from_phrase(&PHRASE.replace("about", "abandon")).is_err());
+        assert!(Mnemonic::from_phrase(&PHRASE.replace("about", "aleo")).is_err());
+
+        // Check valid mnemonics of unsupported lengths are rejected.
+        let mnemonic = MnemonicNative::from_entropy_in(Language::English, &[0u8; 20]).unwrap();
+        assert!(Mnemonic::from_phrase(&mnemonic.to_string()).is_err());
+
+        // Check extra whitespace and capitalization are tolerated.
+        let phrase = format!("  {}  ", PHRASE.to_uppercase().replace(' ', "\n "));
+        assert_eq!(Mnemonic::from_phrase(&phrase).unwrap(), Mnemonic::from_phrase(PHRASE).unwrap());
+    }
+}
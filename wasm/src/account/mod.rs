@@ -20,6 +20,15 @@ pub use address::*;
 pub mod encryptor;
 pub use encryptor::*;
 
+pub mod error;
+pub use error::*;
+
+pub mod key_string;
+pub use key_string::*;
+
+pub mod mnemonic;
+pub use mnemonic::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -14,17 +14,81 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Address, Encryptor, PrivateKeyCiphertext, Signature, ViewKey};
-
-use crate::types::native::{CurrentNetwork, Environment, FromBytes, PrimeField, PrivateKeyNative, ToBytes};
+use crate::{
+    account::{
+        sanitize_key_string,
+        AccountError,
+        Address,
+        Encryptor,
+        KdfParams,
+        PrivateKeyCiphertext,
+        Signature,
+        ViewKey,
+    },
+    record::RecordPlaintext,
+};
+
+use crate::types::native::{
+    CiphertextNative,
+    CurrentNetwork,
+    Environment,
+    FieldNative,
+    FromBits,
+    FromBytes,
+    GroupNative,
+    PrimeField,
+    PrivateKeyNative,
+    SizeInDataBits,
+    ToBits,
+    ToBytes,
+    ViewKeyNative,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use js_sys::Uint8Array;
+use once_cell::sync::OnceCell;
 use rand::{rngs::StdRng, SeedableRng};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
 use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Salt used when deriving local storage keys from a private key
+const LOCAL_STORAGE_KEY_SALT: &[u8] = b"AleoLocalStorageKey0";
+
+/// Salt used when deriving session keys from a private key
+const SESSION_KEY_SALT: &[u8] = b"AleoSessionKey0";
+
+/// Salt used when deriving ephemeral private keys with `PrivateKey::new_ephemeral`
+const EPHEMERAL_KEY_SALT: &[u8] = b"AleoEphemeralKey0";
+
+/// Length in bytes of the byte representation of a private key
+const PRIVATE_KEY_BYTES: usize = 32;
+
+/// Maximum number of private keys which can be generated by `PrivateKey::new_batch`
+const MAX_BATCH_SIZE: u32 = 10_000;
+
+/// Minimum number of characters in a secret accepted by `PrivateKey::to_ciphertext_strict`
+const MIN_STRICT_SECRET_LEN: usize = 12;
+
+/// Minimum number of character classes in a secret accepted by `PrivateKey::to_ciphertext_strict`
+const MIN_STRICT_SECRET_CLASSES: u32 = 3;
 
 /// Private key of an Aleo account
+///
+/// The address derived from the private key is cached after the first derivation, since deriving
+/// it requires a scalar multiplication and wallets request it repeatedly. The cache is not part of
+/// the key's identity and is ignored by equality comparisons. The view key is not cached, as it is
+/// cheap to derive and caching it would keep another copy of secret key material alive
+///
+/// The byte representation of the private key is held alongside the native private key and is
+/// zeroized when the private key is dropped. The native private key is `Copy` and cannot be cleared
+/// in place, so byte level access to the key within this crate goes through the zeroizing buffer
 #[wasm_bindgen]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PrivateKey(PrivateKeyNative);
+#[derive(Clone, Debug)]
+pub struct PrivateKey(PrivateKeyNative, OnceCell<Address>, [u8; PRIVATE_KEY_BYTES]);
 
 #[wasm_bindgen]
 impl PrivateKey {
@@ -34,7 +98,49 @@ impl PrivateKey {
     #[wasm_bindgen(constructor)]
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self(PrivateKeyNative::new(&mut StdRng::from_entropy()).unwrap())
+        Self::from(PrivateKeyNative::new(&mut StdRng::from_entropy()).unwrap())
+    }
+
+    /// Generate many new private keys in a single call using one cryptographically secure random
+    /// number generator
+    ///
+    /// @param {number} count Number of private keys to generate, at most 10,000
+    /// @returns {PrivateKey[] | Error} Newly generated private keys
+    #[wasm_bindgen(js_name = newBatch)]
+    pub fn new_batch(count: u32) -> Result<Vec<PrivateKey>, String> {
+        if count > MAX_BATCH_SIZE {
+            return Err(format!("Cannot generate more than {MAX_BATCH_SIZE} private keys at once, requested {count}"));
+        }
+        let rng = &mut StdRng::from_entropy();
+        (0..count).map(|_| PrivateKeyNative::new(rng).map(Self::from).map_err(|error| error.to_string())).collect()
+    }
+
+    /// Generate a private key the same way as `new`, but with a random number generator seeded from
+    /// the given bytes. Unlike `from_seed_unchecked`, the bytes seed the key generation sampling
+    /// rather than being cast directly into a field element. This should only be used for
+    /// reproducible tests and key derivation pipelines, as the key is only as secret as the seed
+    ///
+    /// @param {Uint8Array} seed 32 byte seed for the random number generator
+    /// @returns {PrivateKey | Error} Private key, or an error if the seed is not 32 bytes
+    #[wasm_bindgen(js_name = fromRngSeed)]
+    pub fn from_rng_seed(seed: &[u8]) -> Result<PrivateKey, String> {
+        let seed: [u8; 32] =
+            seed.try_into().map_err(|_| format!("The seed must be 32 bytes, found {} bytes", seed.len()))?;
+        PrivateKeyNative::new(&mut StdRng::from_seed(seed)).map(Self::from).map_err(|error| error.to_string())
+    }
+
+    /// Generate a new private key whose address is not in a list of blocked addresses. New private
+    /// keys are sampled until one is found or the maximum number of attempts is reached
+    ///
+    /// @param {Address[]} blocked Addresses the private key must not correspond to
+    /// @param {number} max_attempts Maximum number of private keys to sample
+    /// @returns {PrivateKey | Error} Private key whose address is not blocked
+    #[wasm_bindgen(js_name = newExcluding)]
+    pub fn new_excluding(blocked: Vec<Address>, max_attempts: u32) -> Result<PrivateKey, String> {
+        (0..max_attempts)
+            .map(|_| Self::new())
+            .find(|private_key| !blocked.contains(&private_key.to_address()))
+            .ok_or_else(|| format!("Failed to generate an unblocked private key in {max_attempts} attempts"))
     }
 
     /// Get a private key from a series of unchecked bytes
@@ -42,20 +148,64 @@ impl PrivateKey {
     /// @param {Uint8Array} seed Unchecked 32 byte long Uint8Array acting as the seed for the private key
     /// @returns {PrivateKey}
     pub fn from_seed_unchecked(seed: &[u8]) -> PrivateKey {
+        Self::from_seed(seed).unwrap()
+    }
+
+    /// Get a private key from a 32 byte seed as in `from_seed_unchecked`, returning an error rather
+    /// than panicking if the seed is not 32 bytes
+    ///
+    /// @param {Uint8Array} seed 32 byte long Uint8Array acting as the seed for the private key
+    /// @returns {PrivateKey | Error} Private key, or an error with code "INVALID_SEED_LENGTH" if the seed is not 32 bytes
+    #[wasm_bindgen(js_name = fromSeed)]
+    pub fn from_seed(seed: &[u8]) -> Result<PrivateKey, AccountError> {
         // Cast into a fixed-size byte array. Note: This is a **hard** requirement for security.
-        let seed: [u8; 32] = seed.try_into().unwrap();
+        let seed: [u8; 32] = seed.try_into().map_err(|_| AccountError::InvalidSeedLength(seed.len()))?;
         // Recover the field element deterministically.
         let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&seed);
         // Cast and recover the private key from the seed.
-        Self(PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap()).unwrap())
+        let private_key = PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap())
+            .map_err(|_| AccountError::InvalidPrivateKey)?;
+        Ok(Self::from(private_key))
     }
 
     /// Get a private key from a string representation of a private key
     ///
     /// @param {string} seed String representation of a private key
-    /// @returns {PrivateKey}
-    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
-        Self::from_str(private_key).map_err(|_| "Invalid private key".to_string())
+    /// @returns {PrivateKey | Error} Private key, or an error with code "INVALID_PRIVATE_KEY"
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, AccountError> {
+        Self::from_str(private_key).map_err(|_| AccountError::InvalidPrivateKey)
+    }
+
+    /// Get a private key from its byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte representation of a private key produced by `toBytes`
+    /// @returns {PrivateKey | Error} Private key
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<PrivateKey, String> {
+        if bytes.len() != PRIVATE_KEY_BYTES {
+            return Err(format!("A private key must be {PRIVATE_KEY_BYTES} bytes, found {} bytes", bytes.len()));
+        }
+        PrivateKeyNative::from_bytes_le(bytes).map(Self::from).map_err(|_| "Invalid private key bytes".to_string())
+    }
+
+    /// Compare two private keys in constant time. This should be used instead of `==` whenever the
+    /// comparison is security sensitive, as `==` may return as soon as the keys differ and leak
+    /// timing information about the keys
+    ///
+    /// @param {PrivateKey} other Private key to compare against
+    /// @returns {boolean} True if the private keys are equal, false otherwise
+    #[wasm_bindgen(js_name = ctEq)]
+    pub fn ct_eq(&self, other: &PrivateKey) -> bool {
+        self.to_bytes().ct_eq(&other.to_bytes()).into()
+    }
+
+    /// Get the 32 byte representation of the private key. This function should be used very
+    /// carefully as it exposes the private key plaintext
+    ///
+    /// @returns {Uint8Array} Byte representation of the private key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.2.to_vec()
     }
 
     /// Get a string representation of the private key. This function should be used very carefully
@@ -78,7 +228,50 @@ impl PrivateKey {
     ///
     /// @returns {Address}
     pub fn to_address(&self) -> Address {
-        Address::from_private_key(self)
+        *self.1.get_or_init(|| Address::from_private_key(self))
+    }
+
+    /// Derive a deterministic 32 byte key for encrypting local data (such as wallet caches) from the
+    /// private key. The key is derived with HKDF-SHA256 so it does not reveal the private key, and
+    /// different contexts produce independent keys
+    ///
+    /// @param {string} context Context the key will be used in, i.e. "record_cache"
+    /// @returns {Uint8Array} 32 byte key
+    #[wasm_bindgen(js_name = localStorageKey)]
+    pub fn local_storage_key(&self, context: &str) -> Vec<u8> {
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(LOCAL_STORAGE_KEY_SALT), &self.2).expand(context.as_bytes(), &mut key).unwrap();
+        let output = key.to_vec();
+        key.zeroize();
+        output
+    }
+
+    /// Derive a deterministic 32 byte session key from the private key for a time window. The key is
+    /// derived with HKDF-SHA256 from the private key and the window index, so sessions can be rotated
+    /// by moving to the next window, i.e. `Math.floor(Date.now() / windowLength)`
+    ///
+    /// @param {bigint} window Index of the time window
+    /// @returns {Uint8Array} 32 byte session key
+    #[wasm_bindgen(js_name = sessionKey)]
+    pub fn session_key(&self, window: u64) -> Vec<u8> {
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(SESSION_KEY_SALT), &self.2).expand(&window.to_le_bytes(), &mut key).unwrap();
+        let output = key.to_vec();
+        key.zeroize();
+        output
+    }
+
+    /// Derive a stable identifier for the account which a server can store in place of the address.
+    /// The identifier is the HMAC-SHA256 of the address under a server salt, so the same account
+    /// maps to the same identifier on one server but to unrelated identifiers across servers
+    ///
+    /// @param {Uint8Array} server_salt Secret salt held by the server
+    /// @returns {string} Lowercase hex encoding of the account identifier
+    #[wasm_bindgen(js_name = accountId)]
+    pub fn account_id(&self, server_salt: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(server_salt).unwrap();
+        mac.update(self.to_address().to_string().as_bytes());
+        hex::encode(mac.finalize().into_bytes())
     }
 
     /// Sign a message with the private key
@@ -89,6 +282,121 @@ impl PrivateKey {
         Signature::sign(self, message)
     }
 
+    /// Sign a message with a random number generator seeded from the given bytes, so the same
+    /// message and seed always produce the same signature. This is intended for reproducible test
+    /// vectors and deterministic signing protocols. A seed must never be reused with different
+    /// messages, as two signatures sharing a seed reveal the private key
+    ///
+    /// @param {Uint8Array} message Byte array representing the message to sign
+    /// @param {Uint8Array} seed 32 byte seed for the random number generator
+    /// @returns {Signature | Error} Signature of the message, or an error if the seed is not 32 bytes
+    #[wasm_bindgen(js_name = signWithRngSeed)]
+    pub fn sign_with_rng_seed(&self, message: &[u8], seed: &[u8]) -> Result<Signature, String> {
+        let seed: [u8; 32] =
+            seed.try_into().map_err(|_| format!("The seed must be 32 bytes, found {} bytes", seed.len()))?;
+        Ok(Signature::sign_with_rng(&self.0, message, &mut StdRng::from_seed(seed)))
+    }
+
+    /// Sign a string message. The message is encoded as UTF-8 bytes before signing, so the
+    /// signature verifies against the UTF-8 bytes of the message with `Signature.verify`
+    ///
+    /// @param {string} message String message to sign
+    /// @returns {Signature} Signature of the UTF-8 encoding of the message
+    #[wasm_bindgen(js_name = signMessage)]
+    pub fn sign_message(&self, message: &str) -> Signature {
+        self.sign(message.as_bytes())
+    }
+
+    /// Sign an ordered list of addresses, such as the approvers of a multi-party approval. The
+    /// signature depends on the order of the list, so lists which should be order independent must
+    /// be sorted first, i.e. with `sortAddresses`
+    ///
+    /// @param {Address[]} addresses Ordered list of addresses to sign
+    /// @returns {Signature} Signature over the list of addresses
+    #[wasm_bindgen(js_name = signAddresses)]
+    pub fn sign_addresses(&self, addresses: Vec<Address>) -> Signature {
+        self.sign(&Signature::addresses_message(&addresses))
+    }
+
+    /// Sign many messages with the private key in a single call
+    ///
+    /// @param {Uint8Array[]} messages Byte arrays representing the messages to sign
+    /// @returns {Signature[]} Signatures of each message, in the same order as the messages
+    #[wasm_bindgen(js_name = signMany)]
+    pub fn sign_many(&self, messages: Vec<Uint8Array>) -> Vec<Signature> {
+        messages.iter().map(|message| self.sign(&message.to_vec())).collect()
+    }
+
+    /// Get the bytes a message is encoded as before it is hashed during signing. The message bits are
+    /// packed little-endian into field elements, and the hash input during signing is
+    /// `[g_r, pk_sig, pr_sig, address, ...message_fields]`. This returns the little-endian bytes of
+    /// the message fields, which is the only part of the hash input that depends on the message
+    ///
+    /// @param {Uint8Array} message Byte array representing the message to be signed
+    /// @returns {Uint8Array} Little-endian bytes of the field elements the message is encoded as
+    #[wasm_bindgen(js_name = signingPreimage)]
+    pub fn signing_preimage(message: &[u8]) -> Vec<u8> {
+        message
+            .to_bits_le()
+            .chunks(FieldNative::size_in_data_bits())
+            .flat_map(|bits| FieldNative::from_bits_le(bits).unwrap().to_bytes_le().unwrap())
+            .collect()
+    }
+
+    /// Attest that the account owns a record holding at least a minimum amount of microcredits
+    /// without revealing the record. The attestation is a JSON object of the form
+    /// `{ address, commitment, min_amount, signature }` where the signature covers the address,
+    /// record commitment, and minimum amount. Verifiers can check the record exists on chain using
+    /// its commitment
+    ///
+    /// @param {RecordPlaintext} record Record owned by the account
+    /// @param {bigint} min_amount Minimum amount of microcredits to attest to
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {string | Error} JSON attestation of the funds
+    #[wasm_bindgen(js_name = attestFunds)]
+    pub fn attest_funds(
+        &self,
+        record: &RecordPlaintext,
+        min_amount: u64,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<String, String> {
+        let address = self.to_address();
+        if record.owner() != address {
+            return Err("The record is not owned by the private key".to_string());
+        }
+        if record.microcredits() < min_amount {
+            return Err(format!("The record does not contain at least {min_amount} microcredits"));
+        }
+        let commitment = record.commitment(program_id, record_name)?.to_string();
+        let signature = self.sign(&Signature::funds_attestation_message(&address, &commitment, min_amount));
+        Ok(serde_json::json!({
+            "address": address.to_string(),
+            "commitment": commitment,
+            "min_amount": min_amount,
+            "signature": signature.to_string(),
+        })
+        .to_string())
+    }
+
+    /// Sign a payload together with an expiration time to produce a time-limited token of the form
+    /// `base64(payload).base64(signature).expires_at`
+    ///
+    /// @param {Uint8Array} payload Byte array representing the payload of the token
+    /// @param {bigint} expires_at Unix timestamp (in seconds) after which the token is no longer valid
+    /// @returns {string} Token containing the payload, signature, and expiration time
+    #[wasm_bindgen(js_name = signToken)]
+    pub fn sign_token(&self, payload: &[u8], expires_at: u64) -> String {
+        let signature = self.sign(&Signature::token_message(payload, expires_at));
+        format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature.to_bytes_le().unwrap()),
+            expires_at
+        )
+    }
+
     /// Get a new randomly generated private key ciphertext using a secret. The secret is sensitive
     /// and will be needed to decrypt the private key later, so it should be stored securely
     ///
@@ -114,22 +422,134 @@ impl PrivateKey {
         Ok(PrivateKeyCiphertext::from(ciphertext))
     }
 
+    /// Encrypt an existing private key with a secret, rejecting weak secrets. A secret must contain
+    /// at least 12 characters and at least 3 of lowercase letters, uppercase letters, digits, and
+    /// symbols
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @returns {PrivateKeyCiphertext | Error} Ciphertext representation of the private key, or an
+    /// error describing why the secret is too weak
+    #[wasm_bindgen(js_name = toCiphertextStrict)]
+    pub fn to_ciphertext_strict(&self, secret: &str) -> Result<PrivateKeyCiphertext, String> {
+        check_secret_strength(secret)?;
+        self.to_ciphertext(secret)
+    }
+
+    /// Encrypt an existing private key with a secret, stretching the secret with PBKDF2-HMAC-SHA256
+    /// and a random salt to slow down guessing. The number of iterations and the salt are recorded
+    /// in the ciphertext so decryption does not need to be told them
+    ///
+    /// Key stretching runs synchronously, both here and whenever the ciphertext is decrypted, and
+    /// blocks the calling thread for time proportional to the number of iterations. 600,000
+    /// iterations, the OWASP recommendation for PBKDF2-HMAC-SHA256, can take a second or more on
+    /// slower devices, so encryption and decryption with counts this high should be run in a web
+    /// worker rather than on the main thread. Counts above 10,000,000 are rejected so that a
+    /// crafted ciphertext cannot stall decryption indefinitely
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @param {number} iterations Number of PBKDF2 iterations, between 1 and 10,000,000
+    /// @returns {PrivateKeyCiphertext | Error} Ciphertext representation of the private key
+    #[wasm_bindgen(js_name = toCiphertextWithParams)]
+    pub fn to_ciphertext_with_params(&self, secret: &str, iterations: u32) -> Result<PrivateKeyCiphertext, String> {
+        let params = KdfParams::new(iterations)?;
+        let ciphertext = Encryptor::encrypt_private_key_with_params(self, secret, Some(&params))?;
+        Ok(PrivateKeyCiphertext::with_params(ciphertext, params))
+    }
+
+    /// Encrypt a private key stored as a string in a single call, such as when migrating plaintext
+    /// keys to encrypted storage. The parsed private key is only held for the duration of the call
+    ///
+    /// @param {string} plaintext_key String representation of the private key to encrypt
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @returns {PrivateKeyCiphertext | Error} Ciphertext representation of the private key
+    #[wasm_bindgen(js_name = migrateToCiphertext)]
+    pub fn migrate_to_ciphertext(plaintext_key: &str, secret: &str) -> Result<PrivateKeyCiphertext, String> {
+        Self::from_string(plaintext_key)?.to_ciphertext(secret)
+    }
+
+    /// Encrypt the private key so that only the owner of a recipient address can decrypt it. This
+    /// can be used for key escrow without sharing a secret between the parties
+    ///
+    /// @param {Address} recipient Address of the account able to decrypt the private key
+    /// @returns {string | Error} Ciphertext of the private key encrypted to the recipient
+    #[wasm_bindgen(js_name = encryptToAddress)]
+    pub fn encrypt_to_address(&self, recipient: &Address) -> Result<String, String> {
+        let (nonce, ciphertext) =
+            Encryptor::encrypt_private_key_to_address(self, recipient).map_err(|_| "Encryption failed".to_string())?;
+        Ok(format!("{nonce}.{ciphertext}"))
+    }
+
+    /// Decrypt a private key which was encrypted to the address of the recipient private key
+    ///
+    /// @param {string} ciphertext Ciphertext produced by `encryptToAddress`
+    /// @param {PrivateKey} recipient_private_key Private key of the recipient address
+    /// @returns {PrivateKey | Error} Decrypted private key
+    #[wasm_bindgen(js_name = decryptFromEscrow)]
+    pub fn decrypt_from_escrow(ciphertext: &str, recipient_private_key: &PrivateKey) -> Result<PrivateKey, String> {
+        let (nonce, ciphertext) = ciphertext.split_once('.').ok_or_else(|| "Invalid escrow ciphertext".to_string())?;
+        let nonce = GroupNative::from_str(nonce).map_err(|_| "Invalid escrow ciphertext".to_string())?;
+        let ciphertext = CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid escrow ciphertext".to_string())?;
+        let view_key = ViewKeyNative::try_from(recipient_private_key.0).map_err(|e| e.to_string())?;
+        let private_key = Encryptor::decrypt_private_key_from_escrow(&nonce, &ciphertext, &view_key)
+            .map_err(|_| "Decryption failed".to_string())?;
+        Ok(Self::from(private_key))
+    }
+
     /// Get private key from a private key ciphertext and secret originally used to encrypt it
     ///
     /// @param {PrivateKeyCiphertext} ciphertext Ciphertext representation of the private key
     /// @param {string} secret Secret originally used to encrypt the private key
     /// @returns {PrivateKey | Error} Private key
     #[wasm_bindgen(js_name = fromPrivateKeyCiphertext)]
-    pub fn from_private_key_ciphertext(ciphertext: &PrivateKeyCiphertext, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(ciphertext, secret)
-            .map_err(|_| "Decryption failed".to_string())?;
+    pub fn from_private_key_ciphertext(
+        ciphertext: &PrivateKeyCiphertext,
+        secret: &str,
+    ) -> Result<PrivateKey, AccountError> {
+        let private_key = Encryptor::decrypt_private_key_with_params(ciphertext, secret, ciphertext.kdf_params())
+            .map_err(|_| AccountError::Decryption)?;
         Ok(Self::from(private_key))
     }
 }
 
+impl PrivateKey {
+    /// Derive a deterministic ephemeral private key and its address from a seed of any length, for
+    /// one-time use such as ECIES style message encryption. The same seed always produces the same
+    /// keypair, so a seed must never be reused for a second message
+    pub fn new_ephemeral(seed: &[u8]) -> (PrivateKey, Address) {
+        let mut rng_seed = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(EPHEMERAL_KEY_SALT), seed).expand(b"ephemeral", &mut rng_seed).unwrap();
+        let private_key = Self::from(PrivateKeyNative::new(&mut StdRng::from_seed(rng_seed)).unwrap());
+        rng_seed.zeroize();
+        let address = private_key.to_address();
+        (private_key, address)
+    }
+}
+
+// Check a secret meets the minimum length and number of character classes for strict encryption
+fn check_secret_strength(secret: &str) -> Result<(), String> {
+    let (length, classes) = secret.chars().fold((0usize, 0u8), |(length, classes), c| {
+        let class = (c.is_ascii_lowercase() as u8)
+            | ((c.is_ascii_uppercase() as u8) << 1)
+            | ((c.is_ascii_digit() as u8) << 2)
+            | ((!c.is_ascii_alphanumeric() as u8) << 3);
+        (length + 1, classes | class)
+    });
+    if length < MIN_STRICT_SECRET_LEN || classes.count_ones() < MIN_STRICT_SECRET_CLASSES {
+        return Err(format!(
+            "The secret is too weak: it must contain at least {MIN_STRICT_SECRET_LEN} characters and at least \
+             {MIN_STRICT_SECRET_CLASSES} of lowercase letters, uppercase letters, digits, and symbols, found {length} \
+             characters and {} character classes",
+            classes.count_ones()
+        ));
+    }
+    Ok(())
+}
+
 impl From<PrivateKeyNative> for PrivateKey {
     fn from(private_key: PrivateKeyNative) -> Self {
-        Self(private_key)
+        let mut bytes = [0u8; PRIVATE_KEY_BYTES];
+        private_key.write_le(&mut bytes[..]).unwrap();
+        Self(private_key, OnceCell::new(), bytes)
     }
 }
 
@@ -148,10 +568,25 @@ impl FromStr for PrivateKey {
     type Err = anyhow::Error;
 
     fn from_str(private_key: &str) -> Result<Self, Self::Err> {
-        Ok(Self(PrivateKeyNative::from_str(private_key)?))
+        Ok(Self::from(PrivateKeyNative::from_str(&sanitize_key_string(private_key))?))
     }
 }
 
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        self.2.zeroize();
+    }
+}
+
+// Equality is not constant time, so `PrivateKey::ct_eq` should be used for security sensitive comparisons
+impl PartialEq for PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for PrivateKey {}
+
 impl fmt::Display for PrivateKey {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -204,6 +639,56 @@ mod tests {
         }
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_ct_eq() {
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            let copy = PrivateKey::from_bytes(&private_key.to_bytes()).unwrap();
+            let other = PrivateKey::new();
+
+            // Check the constant time comparison agrees with `==`.
+            assert_eq!(private_key.ct_eq(&copy), private_key == copy);
+            assert_eq!(private_key.ct_eq(&other), private_key == other);
+            assert!(private_key.ct_eq(&copy));
+            assert!(!private_key.ct_eq(&other));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_zeroize_on_drop() {
+        let mut private_key = core::mem::ManuallyDrop::new(PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap());
+        let pointer = private_key.2.as_ptr();
+        let key_bytes = || unsafe { core::slice::from_raw_parts(pointer, PRIVATE_KEY_BYTES) };
+        assert_eq!(key_bytes(), private_key.to_bytes().as_slice());
+
+        // Check the key bytes are cleared once the private key is dropped.
+        unsafe { core::mem::ManuallyDrop::drop(&mut private_key) };
+        assert!(key_bytes().iter().all(|byte| *byte == 0));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bytes_round_trip() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let bytes = private_key.to_bytes();
+        assert_eq!(bytes.len(), PRIVATE_KEY_BYTES);
+
+        // Check the key round trips and matches the string representation.
+        let recovered = PrivateKey::from_bytes(&bytes).unwrap();
+        assert_eq!(recovered, private_key);
+        assert_eq!(recovered.to_string(), ALEO_PRIVATE_KEY);
+        assert_eq!(recovered.to_address().to_string(), ALEO_ADDRESS);
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::new();
+            assert_eq!(PrivateKey::from_bytes(&private_key.to_bytes()).unwrap(), private_key);
+        }
+
+        // Check inputs of the wrong length are rejected.
+        assert_eq!(PrivateKey::from_bytes(&bytes[..31]).unwrap_err(), "A private key must be 32 bytes, found 31 bytes");
+        assert!(PrivateKey::from_bytes(&[bytes.clone(), vec![0]].concat()).is_err());
+        assert!(PrivateKey::from_bytes(&[]).is_err());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_from_seed_unchecked() {
         for _ in 0..ITERATIONS {
@@ -213,6 +698,7 @@ mod tests {
             // Ensure the private key is deterministically recoverable.
             let expected = PrivateKey::from_seed_unchecked(&seed);
             assert_eq!(expected, PrivateKey::from_seed_unchecked(&seed));
+            assert_eq!(expected, PrivateKey::from_seed(&seed).unwrap());
         }
     }
 
@@ -229,6 +715,149 @@ mod tests {
         }
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_local_storage_key() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let key = private_key.local_storage_key("record_cache");
+        assert_eq!(key.len(), 32);
+
+        // Check the key is deterministic.
+        assert_eq!(key, PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap().local_storage_key("record_cache"));
+        // Check the key depends on the context and the private key.
+        assert_ne!(key, private_key.local_storage_key("settings"));
+        assert_ne!(key, PrivateKey::new().local_storage_key("record_cache"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_session_key() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let key = private_key.session_key(1_000);
+        assert_eq!(key.len(), 32);
+
+        // Check the same window yields the same key and adjacent windows rotate the key.
+        assert_eq!(key, PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap().session_key(1_000));
+        assert_ne!(key, private_key.session_key(999));
+        assert_ne!(key, private_key.session_key(1_001));
+        assert_ne!(key, PrivateKey::new().session_key(1_000));
+        // Check session keys are independent of local storage keys.
+        assert_ne!(private_key.session_key(0), private_key.local_storage_key(""));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_account_id() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let account_id = private_key.account_id(b"server_one");
+        assert_eq!(account_id.len(), 64);
+
+        // Check the identifier is deterministic for a salt.
+        assert_eq!(account_id, PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap().account_id(b"server_one"));
+        // Check the identifier differs across salts and accounts.
+        assert_ne!(account_id, private_key.account_id(b"server_two"));
+        assert_ne!(account_id, PrivateKey::new().account_id(b"server_one"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_signing_preimage() {
+        // Check a short message is packed into a single little-endian field element.
+        let mut expected = vec![0u8; 32];
+        expected[..2].copy_from_slice(b"hi");
+        assert_eq!(PrivateKey::signing_preimage(b"hi"), expected);
+
+        // Check a message larger than a field element's data capacity spans multiple fields.
+        let preimage = PrivateKey::signing_preimage(&[0xffu8; 32]);
+        assert_eq!(preimage.len(), 64);
+        assert_eq!(preimage, PrivateKey::signing_preimage(&[0xffu8; 32]));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_new_excluding() {
+        let blocked = vec![PrivateKey::new().to_address(), PrivateKey::new().to_address()];
+        let private_key = PrivateKey::new_excluding(blocked.clone(), 10).unwrap();
+        assert!(!blocked.contains(&private_key.to_address()));
+
+        // Check no private key is generated without any attempts.
+        assert!(PrivateKey::new_excluding(blocked, 0).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_attest_funds() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6").unwrap();
+        let record = RecordPlaintext::from_string(&format!(
+            "{{ owner: {}.private, microcredits: 100u64.private, _nonce: 0group.public }}",
+            private_key.to_address()
+        ))
+        .unwrap();
+        let attestation = private_key.attest_funds(&record, 50, "credits.aleo", "credits").unwrap();
+        assert!(crate::verify_funds_attestation(&attestation).unwrap());
+
+        // Check a raised minimum is rejected.
+        let raised = attestation.replace("\"min_amount\":50", "\"min_amount\":500");
+        assert_ne!(raised, attestation);
+        assert!(!crate::verify_funds_attestation(&raised).unwrap());
+
+        // Check attestations are refused for insufficient funds and records owned by other accounts.
+        assert!(private_key.attest_funds(&record, 101, "credits.aleo", "credits").is_err());
+        assert!(PrivateKey::new().attest_funds(&record, 50, "credits.aleo", "credits").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_ciphertext_strict() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let ciphertext = private_key.to_ciphertext_strict("Correct-Horse-9").unwrap();
+        assert_eq!(ciphertext.decrypt_to_private_key("Correct-Horse-9").unwrap(), private_key);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_ciphertext_strict_weak_secret() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+
+        // Check short secrets and secrets with too few character classes are rejected.
+        let error = private_key.to_ciphertext_strict("mypassword").unwrap_err();
+        assert!(error.starts_with("The secret is too weak"));
+        assert!(private_key.to_ciphertext_strict("Sh0rt!").is_err());
+        assert!(private_key.to_ciphertext_strict("alllowercaseletters").is_err());
+        assert!(private_key.to_ciphertext_strict("lowercase123456").is_err());
+
+        // Check the non-strict path still accepts weak secrets.
+        assert!(private_key.to_ciphertext("mypassword").is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_migrate_to_ciphertext() {
+        let ciphertext = PrivateKey::migrate_to_ciphertext(ALEO_PRIVATE_KEY, "mypassword").unwrap();
+        let recovered = ciphertext.decrypt_to_private_key("mypassword").unwrap();
+        assert_eq!(recovered.to_string(), ALEO_PRIVATE_KEY);
+
+        // Check an invalid private key string is an error.
+        assert!(PrivateKey::migrate_to_ciphertext("APrivateKey1invalid", "mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_encrypt_to_address() {
+        let private_key = PrivateKey::new();
+        let recipient = PrivateKey::new();
+        let ciphertext = private_key.encrypt_to_address(&recipient.to_address()).unwrap();
+
+        // Check the recipient recovers the private key.
+        let recovered = PrivateKey::decrypt_from_escrow(&ciphertext, &recipient).unwrap();
+        assert_eq!(private_key, recovered);
+
+        // Check encryption is randomized.
+        assert_ne!(ciphertext, private_key.encrypt_to_address(&recipient.to_address()).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_from_escrow_wrong_recipient() {
+        let private_key = PrivateKey::new();
+        let ciphertext = private_key.encrypt_to_address(&PrivateKey::new().to_address()).unwrap();
+        let wrong_recipient = PrivateKey::new();
+        assert!(PrivateKey::decrypt_from_escrow(&ciphertext, &wrong_recipient).is_err());
+
+        // Check malformed ciphertexts fail.
+        assert!(PrivateKey::decrypt_from_escrow("not a ciphertext", &wrong_recipient).is_err());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_signature() {
         for _ in 0..ITERATIONS {
@@ -244,4 +873,111 @@ mod tests {
             assert!(signature.verify_bytes(&private_key.to_address(), &message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_new_ephemeral() {
+        let (private_key, address) = PrivateKey::new_ephemeral(b"message 1");
+        assert_eq!(private_key.to_address(), address);
+
+        // Check the same seed produces the same keypair and a different seed a different keypair.
+        let (same_private_key, same_address) = PrivateKey::new_ephemeral(b"message 1");
+        assert_eq!(private_key, same_private_key);
+        assert_eq!(address, same_address);
+        let (other_private_key, other_address) = PrivateKey::new_ephemeral(b"message 2");
+        assert_ne!(private_key, other_private_key);
+        assert_ne!(address, other_address);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_new_batch() {
+        let private_keys = PrivateKey::new_batch(1_000).unwrap();
+        assert_eq!(private_keys.len(), 1_000);
+
+        // Check every key is distinct and round trips through its string representation.
+        let strings: std::collections::HashSet<String> = private_keys.iter().map(|key| key.to_string()).collect();
+        assert_eq!(strings.len(), 1_000);
+        for private_key in &private_keys {
+            assert_eq!(&PrivateKey::from_string(&private_key.to_string()).unwrap(), private_key);
+        }
+
+        assert!(PrivateKey::new_batch(0).unwrap().is_empty());
+        assert!(PrivateKey::new_batch(MAX_BATCH_SIZE + 1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_rng_seed() {
+        let private_key = PrivateKey::from_rng_seed(&[1u8; 32]).unwrap();
+        assert_eq!(private_key, PrivateKey::from_rng_seed(&[1u8; 32]).unwrap());
+        assert_ne!(private_key, PrivateKey::from_rng_seed(&[2u8; 32]).unwrap());
+
+        // Check the seed is not used directly as the seed of the private key.
+        assert_ne!(private_key, PrivateKey::from_seed_unchecked(&[1u8; 32]));
+
+        // Check seeds of the wrong length are rejected.
+        assert!(PrivateKey::from_rng_seed(&[1u8; 31]).is_err());
+        assert!(PrivateKey::from_rng_seed(&[1u8; 33]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_with_rng_seed() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let signature = private_key.sign_with_rng_seed(b"message", &[3u8; 32]).unwrap();
+        assert!(signature.verify(&private_key.to_address(), b"message"));
+
+        // Check the same message and seed produce the same signature.
+        let repeated = private_key.sign_with_rng_seed(b"message", &[3u8; 32]).unwrap();
+        assert_eq!(signature.to_string(), repeated.to_string());
+
+        // Check a different seed or message produces a different signature.
+        let other_seed = private_key.sign_with_rng_seed(b"message", &[4u8; 32]).unwrap();
+        assert_ne!(signature.to_string(), other_seed.to_string());
+        assert!(other_seed.verify(&private_key.to_address(), b"message"));
+        let other_message = private_key.sign_with_rng_seed(b"other", &[3u8; 32]).unwrap();
+        assert_ne!(signature.to_string(), other_message.to_string());
+
+        assert!(private_key.sign_with_rng_seed(b"message", &[3u8; 16]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_many() {
+        let private_key = PrivateKey::new();
+        let messages: Vec<Vec<u8>> = (0..16).map(|i| vec![i; i as usize]).collect();
+
+        let signatures =
+            private_key.sign_many(messages.iter().map(|message| Uint8Array::from(message.as_slice())).collect());
+        assert_eq!(signatures.len(), messages.len());
+
+        // Check each signature verifies against its own message only.
+        for (i, signature) in signatures.iter().enumerate() {
+            assert!(signature.verify(&private_key.to_address(), &messages[i]));
+            assert!(!signature.verify(&private_key.to_address(), &messages[(i + 1) % messages.len()]));
+        }
+        assert!(private_key.sign_many(vec![]).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_cached_derivations() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let address = private_key.to_address();
+        let view_key = private_key.to_view_key();
+        assert_eq!(ALEO_ADDRESS, address.to_string());
+        assert_eq!(ALEO_VIEW_KEY, view_key.to_string());
+
+        // The address should be cached by the first derivation and repeated calls return equal values
+        assert_eq!(private_key.1.get(), Some(&address));
+        assert_eq!(address, private_key.to_address());
+        assert_eq!(view_key, private_key.to_view_key());
+        assert!(PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap().1.get().is_none());
+
+        // Populating the cache should not affect equality or the string representation
+        let fresh = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(private_key, fresh);
+        assert_eq!(private_key.to_string(), fresh.to_string());
+
+        // Clones should carry the cached address along with the key
+        let cloned = private_key.clone();
+        assert_eq!(private_key, cloned);
+        assert_eq!(cloned.1.get(), Some(&address));
+        assert_eq!(address, cloned.to_address());
+    }
 }
@@ -14,16 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Encryptor, PrivateKey};
+use crate::account::{
+    encryptor::{KdfParams, KDF_SALT_LEN},
+    Encryptor,
+    PrivateKey,
+};
 
-use crate::types::native::CiphertextNative;
+use crate::types::native::{CiphertextNative, ToBytes};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
-
-/// Private Key in ciphertext form
+use zeroize::Zeroize;
+
+/// Name of the key derivation function in the string representation of a private key ciphertext
+const KDF_SCHEME: &str = "pbkdf2-sha256";
+
+/// Separator between the fields of the string representation of a private key ciphertext
+const KDF_SEPARATOR: char = '$';
+
+/// Private Key in ciphertext form, along with the PBKDF2-HMAC-SHA256 parameters used to derive the
+/// encryption key from the secret, if any. Ciphertexts encrypted without key stretching use the
+/// plain `ciphertext1...` string representation, while others are prefixed with the key derivation
+/// function, number of iterations and hex encoded salt, i.e.
+/// `pbkdf2-sha256$600000$<salt>$ciphertext1...`
+///
+/// The byte representation of the ciphertext is held alongside the native ciphertext and is
+/// zeroized when the ciphertext is dropped, as the native ciphertext cannot be cleared in place
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PrivateKeyCiphertext(CiphertextNative);
+pub struct PrivateKeyCiphertext(CiphertextNative, Option<KdfParams>, Vec<u8>);
 
 #[wasm_bindgen]
 impl PrivateKeyCiphertext {
@@ -47,18 +65,70 @@ impl PrivateKeyCiphertext {
     /// @returns {PrivateKey | Error} Private key
     #[wasm_bindgen(js_name = decryptToPrivateKey)]
     pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(&self.0, secret)
+        let private_key = Encryptor::decrypt_private_key_with_params(&self.0, secret, self.1.as_ref())
             .map_err(|_| "Decryption failed - ciphertext was not a private key")?;
         Ok(PrivateKey::from(private_key))
     }
 
-    /// Returns the ciphertext string
+    /// Re-encrypt the private key under a new secret, such as when a user changes their password.
+    /// The private key is decrypted and re-encrypted entirely within WebAssembly and is never
+    /// exposed as a `PrivateKey`. The new ciphertext uses the same number of key stretching
+    /// iterations with a fresh salt
+    ///
+    /// @param {string} old_secret Secret the private key is currently encrypted with
+    /// @param {string} new_secret Secret to encrypt the private key with
+    /// @returns {PrivateKeyCiphertext | Error} Private key ciphertext encrypted with the new secret
+    pub fn reencrypt(&self, old_secret: &str, new_secret: &str) -> Result<PrivateKeyCiphertext, String> {
+        let params = self.1.map(|params| KdfParams::new(params.iterations())).transpose()?;
+        let ciphertext = Encryptor::reencrypt_private_key_with_secret(
+            &self.0,
+            old_secret,
+            new_secret,
+            self.1.as_ref(),
+            params.as_ref(),
+        )
+        .map_err(|_| "Decryption failed - the old secret is incorrect")?;
+        Ok(Self::new(ciphertext, params))
+    }
+
+    /// Decrypt a batch of private key ciphertexts encrypted under the same secret, such as the
+    /// entries of a keyring. Ciphertexts sharing key derivation parameters, such as those encrypted
+    /// without key stretching, only derive the key material from the secret once
+    ///
+    /// @param {PrivateKeyCiphertext[]} ciphertexts Private key ciphertexts to decrypt
+    /// @param {string} secret Secret used to encrypt the private keys
+    /// @returns {PrivateKey[] | Error} Private keys in the same order as the ciphertexts
+    #[wasm_bindgen(js_name = decryptMany)]
+    pub fn decrypt_many(ciphertexts: Vec<PrivateKeyCiphertext>, secret: &str) -> Result<Vec<PrivateKey>, String> {
+        let ciphertexts =
+            ciphertexts.into_iter().map(|ciphertext| (ciphertext.0.clone(), ciphertext.1)).collect::<Vec<_>>();
+        let private_keys = Encryptor::decrypt_private_keys_with_secret(&ciphertexts, secret)?;
+        Ok(private_keys.into_iter().map(PrivateKey::from).collect())
+    }
+
+    /// Get the number of PBKDF2 iterations used to derive the encryption key from the secret
+    ///
+    /// @returns {number} Number of key stretching iterations, zero if the secret was not stretched
+    pub fn iterations(&self) -> u32 {
+        self.1.map_or(0, |params| params.iterations())
+    }
+
+    /// Returns the ciphertext string. The string can be persisted, i.e. to disk or IndexedDB, and
+    /// parsed back with `fromString` without losing any information
     ///
     /// @returns {string} Ciphertext string
     #[allow(clippy::inherent_to_string)]
     #[wasm_bindgen(js_name = toString)]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        match &self.1 {
+            Some(params) => format!(
+                "{KDF_SCHEME}{KDF_SEPARATOR}{}{KDF_SEPARATOR}{}{KDF_SEPARATOR}{}",
+                params.iterations(),
+                hex::encode(params.salt()),
+                self.0
+            ),
+            None => self.0.to_string(),
+        }
     }
 
     /// Creates a PrivateKeyCiphertext from a string
@@ -71,9 +141,27 @@ impl PrivateKeyCiphertext {
     }
 }
 
+impl PrivateKeyCiphertext {
+    // Create a private key ciphertext, keeping a copy of its bytes to zeroize on drop
+    fn new(ciphertext: CiphertextNative, params: Option<KdfParams>) -> Self {
+        let bytes = ciphertext.to_bytes_le().unwrap();
+        Self(ciphertext, params, bytes)
+    }
+
+    // Create a private key ciphertext encrypted with a secret stretched with the given parameters
+    pub(crate) fn with_params(ciphertext: CiphertextNative, params: KdfParams) -> Self {
+        Self::new(ciphertext, Some(params))
+    }
+
+    // Get the parameters used to stretch the secret, if any
+    pub(crate) fn kdf_params(&self) -> Option<&KdfParams> {
+        self.1.as_ref()
+    }
+}
+
 impl From<CiphertextNative> for PrivateKeyCiphertext {
     fn from(ciphertext: CiphertextNative) -> Self {
-        Self(ciphertext)
+        Self::new(ciphertext, None)
     }
 }
 
@@ -81,7 +169,24 @@ impl TryFrom<String> for PrivateKeyCiphertext {
     type Error = String;
 
     fn try_from(ciphertext: String) -> Result<Self, Self::Error> {
-        Ok(Self(CiphertextNative::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?))
+        let fields = ciphertext.split(KDF_SEPARATOR).collect::<Vec<_>>();
+        let (params, ciphertext) = match fields.as_slice() {
+            [ciphertext] => (None, *ciphertext),
+            [KDF_SCHEME, iterations, salt, ciphertext] => {
+                let iterations = u32::from_str(iterations).map_err(|_| "Invalid ciphertext".to_string())?;
+                let mut salt_bytes = [0u8; KDF_SALT_LEN];
+                hex::decode_to_slice(salt, &mut salt_bytes).map_err(|_| "Invalid ciphertext".to_string())?;
+                (Some(KdfParams::from_parts(iterations, salt_bytes)?), *ciphertext)
+            }
+            _ => return Err("Invalid ciphertext".to_string()),
+        };
+        Ok(Self::new(CiphertextNative::from_str(ciphertext).map_err(|_| "Invalid ciphertext".to_string())?, params))
+    }
+}
+
+impl Drop for PrivateKeyCiphertext {
+    fn drop(&mut self) {
+        self.2.zeroize();
     }
 }
 
@@ -109,6 +214,20 @@ mod tests {
         assert_eq!(private_key_ciphertext, private_key_ciphertext_2);
     }
 
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_string_persistence() {
+        let private_key = PrivateKey::new();
+        for ciphertext in [
+            private_key.to_ciphertext("mypassword").unwrap(),
+            private_key.to_ciphertext_with_params("mypassword", 100).unwrap(),
+        ] {
+            let stored = ciphertext.to_string();
+            let restored = PrivateKeyCiphertext::from_string(stored.clone()).unwrap();
+            assert_eq!(restored.to_string(), stored);
+            assert_eq!(restored.decrypt_to_private_key("mypassword").unwrap(), private_key);
+        }
+    }
+
     #[wasm_bindgen_test]
     fn test_private_key_from_string_decryption_edge_cases() {
         let private_key =
@@ -126,6 +245,95 @@ mod tests {
         assert!(PrivateKeyCiphertext::from_string(bad_ciphertext.to_string()).is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_decrypt_many() {
+        let private_keys = vec![PrivateKey::new(), PrivateKey::new(), PrivateKey::new()];
+        let ciphertexts = private_keys
+            .iter()
+            .map(|private_key| PrivateKeyCiphertext::encrypt_private_key(private_key, "mypassword").unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(PrivateKeyCiphertext::decrypt_many(ciphertexts.clone(), "mypassword").unwrap(), private_keys);
+
+        // Check the batch fails if any entry was encrypted under a different secret.
+        let mut mixed = ciphertexts;
+        mixed.push(PrivateKeyCiphertext::encrypt_private_key(&PrivateKey::new(), "otherpassword").unwrap());
+        assert!(PrivateKeyCiphertext::decrypt_many(mixed, "mypassword").is_err());
+        assert!(PrivateKeyCiphertext::decrypt_many(vec![], "mypassword").unwrap().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_with_iterations() {
+        let private_key = PrivateKey::new();
+        let ciphertext = private_key.to_ciphertext_with_params("mypassword", 1000).unwrap();
+        assert_eq!(ciphertext.iterations(), 1000);
+        assert_eq!(ciphertext.decrypt_to_private_key("mypassword").unwrap(), private_key);
+        assert!(ciphertext.decrypt_to_private_key("badpassword").is_err());
+
+        // Check the parameters round trip through the string representation and are used to decrypt.
+        let string = ciphertext.to_string();
+        let fields = string.split('$').collect::<Vec<_>>();
+        assert_eq!(fields[..2], ["pbkdf2-sha256", "1000"]);
+        assert_eq!(fields[2].len(), 2 * KDF_SALT_LEN);
+        assert!(fields[3].starts_with("ciphertext1"));
+        let parsed = PrivateKeyCiphertext::from_string(string.clone()).unwrap();
+        assert_eq!(parsed, ciphertext);
+        assert_eq!(parsed.iterations(), 1000);
+        assert_eq!(PrivateKey::from_private_key_ciphertext(&parsed, "mypassword").unwrap(), private_key);
+
+        // Check the same key and secret encrypt with a different salt each time.
+        let other = private_key.to_ciphertext_with_params("mypassword", 1000).unwrap().to_string();
+        assert_ne!(other.split('$').nth(2), fields.get(2).copied());
+
+        // Check a ciphertext read with the wrong number of iterations or salt does not decrypt to the key.
+        let wrong_iterations = PrivateKeyCiphertext::from_string(string.replacen("$1000$", "$999$", 1)).unwrap();
+        assert!(wrong_iterations.decrypt_to_private_key("mypassword").map_or(true, |key| key != private_key));
+        let wrong_salt = string.replacen(fields[2], &"00".repeat(KDF_SALT_LEN), 1);
+        let wrong_salt = PrivateKeyCiphertext::from_string(wrong_salt).unwrap();
+        assert!(wrong_salt.decrypt_to_private_key("mypassword").map_or(true, |key| key != private_key));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_iterations_edge_cases() {
+        let private_key = PrivateKey::new();
+
+        // Check the default encryption does not stretch the secret and uses the plain string representation.
+        let ciphertext = private_key.to_ciphertext("mypassword").unwrap();
+        assert_eq!(ciphertext.iterations(), 0);
+        assert!(ciphertext.to_string().starts_with("ciphertext1"));
+
+        // Check zero iterations, too many iterations and malformed prefixes are rejected.
+        assert!(private_key.to_ciphertext_with_params("mypassword", 0).is_err());
+        assert!(private_key.to_ciphertext_with_params("mypassword", 10_000_001).is_err());
+        let string = private_key.to_ciphertext_with_params("mypassword", 10).unwrap().to_string();
+        assert!(PrivateKeyCiphertext::from_string(string.replacen("$10$", "$x$", 1)).is_err());
+        assert!(PrivateKeyCiphertext::from_string(string.replacen("$10$", "$0$", 1)).is_err());
+        assert!(PrivateKeyCiphertext::from_string(string.replacen("$10$", "$10000001$", 1)).is_err());
+        assert!(PrivateKeyCiphertext::from_string(string.replacen("pbkdf2-sha256", "scrypt", 1)).is_err());
+        assert!(PrivateKeyCiphertext::from_string(string.replacen("$10$", "$10$00", 1)).is_err());
+        assert!(PrivateKeyCiphertext::from_string(format!("10${}", ciphertext.to_string())).is_err());
+
+        // Check re-encryption keeps the number of iterations with a fresh salt.
+        let parsed = PrivateKeyCiphertext::from_string(string).unwrap();
+        let reencrypted = parsed.reencrypt("mypassword", "newpassword").unwrap();
+        assert_eq!(reencrypted.iterations(), 10);
+        assert_ne!(reencrypted.kdf_params().unwrap().salt(), parsed.kdf_params().unwrap().salt());
+        assert_eq!(reencrypted.decrypt_to_private_key("newpassword").unwrap(), private_key);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_reencrypt() {
+        let private_key = PrivateKey::new();
+        let ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "oldpassword").unwrap();
+        let reencrypted = ciphertext.reencrypt("oldpassword", "newpassword").unwrap();
+
+        // Check the new secret recovers the original key and the old secret no longer works.
+        assert_eq!(reencrypted.decrypt_to_private_key("newpassword").unwrap(), private_key);
+        assert!(reencrypted.decrypt_to_private_key("oldpassword").is_err());
+
+        // Check a wrong old secret fails without producing a ciphertext.
+        assert!(ciphertext.reencrypt("wrongpassword", "newpassword").is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_encrypt_and_decrypt() {
         let private_key = PrivateKey::new();
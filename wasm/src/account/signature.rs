@@ -14,17 +14,39 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Address, PrivateKey};
+use crate::account::{Address, Encryptor, PrivateKey, PrivateKeyCiphertext};
 
-use crate::types::native::SignatureNative;
+use crate::types::native::{
+    FieldNative,
+    FromBytes,
+    LiteralNative,
+    PlaintextNative,
+    PrivateKeyNative,
+    SignatureNative,
+    ToBytes,
+};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
 use core::{fmt, ops::Deref, str::FromStr};
-use rand::{rngs::StdRng, SeedableRng};
+use js_sys::Array;
+use once_cell::sync::Lazy;
+use rand::{rngs::StdRng, CryptoRng, Rng, SeedableRng};
+use std::sync::Mutex;
 use wasm_bindgen::prelude::*;
 
 /// Cryptographic signature of a message signed by an Aleo account
 #[wasm_bindgen]
 pub struct Signature(SignatureNative);
 
+/// Header used for JWT-like tokens produced from Aleo signatures
+const JWT_LIKE_HEADER: &str = r#"{"alg":"AleoSchnorr","typ":"JWT"}"#;
+
+/// Random number generator shared by all signing operations, seeded from entropy on first use and
+/// re-seeded with `Signature::reseed_rng`
+static SIGNING_RNG: Lazy<Mutex<StdRng>> = Lazy::new(|| Mutex::new(StdRng::from_entropy()));
+
 #[wasm_bindgen]
 impl Signature {
     /// Sign a message with a private key
@@ -33,7 +55,41 @@ impl Signature {
     /// @param {Uint8Array} message Byte representation of the message to sign
     /// @returns {Signature} Signature of the message
     pub fn sign(private_key: &PrivateKey, message: &[u8]) -> Self {
-        Self(SignatureNative::sign_bytes(private_key, message, &mut StdRng::from_entropy()).unwrap())
+        Self::sign_native(private_key, message)
+    }
+
+    /// Sign a message with a private key held in an encrypted private key ciphertext. The private
+    /// key is decrypted, used, and dropped entirely within WebAssembly so its plaintext is never
+    /// exposed to JavaScript
+    ///
+    /// @param {PrivateKeyCiphertext} ciphertext Ciphertext of the private key to sign the message with
+    /// @param {string} secret Secret originally used to encrypt the private key
+    /// @param {Uint8Array} message Byte representation of the message to sign
+    /// @returns {Signature | Error} Signature of the message
+    #[wasm_bindgen(js_name = signWithCiphertext)]
+    pub fn sign_with_ciphertext(
+        ciphertext: &PrivateKeyCiphertext,
+        secret: &str,
+        message: &[u8],
+    ) -> Result<Signature, String> {
+        let private_key = Encryptor::decrypt_private_key_with_params(ciphertext, secret, ciphertext.kdf_params())
+            .map_err(|_| "Decryption failed".to_string())?;
+        Ok(Self::sign_native(&private_key, message))
+    }
+
+    /// Re-seed the random number generator used for signing from fresh entropy. Long-lived
+    /// applications can call this periodically to rotate the signing randomness
+    #[wasm_bindgen(js_name = reseedRng)]
+    pub fn reseed_rng() {
+        *SIGNING_RNG.lock().unwrap() = StdRng::from_entropy();
+    }
+
+    /// Get the address of the signer, which is derived from the compute key embedded in the signature
+    ///
+    /// @returns {Address} Address of the account which produced the signature
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address::from(self.0.compute_key().to_address())
     }
 
     /// Verify a signature of a message with an address
@@ -45,6 +101,71 @@ impl Signature {
         self.0.verify_bytes(address, message)
     }
 
+    /// Verify a signature of a string message. The message is encoded as UTF-8 bytes before
+    /// verifying, matching `PrivateKey.signMessage`
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} message String message to verify
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyMessage)]
+    pub fn verify_message(&self, address: &Address, message: &str) -> bool {
+        self.verify(address, message.as_bytes())
+    }
+
+    /// Verify a signature of a message encoded as a standard base64 string
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} message_b64 Base64 encoding of the message to verify
+    /// @returns {boolean | Error} True if the signature is valid, false otherwise. An error is
+    /// returned if the message is not valid base64
+    #[wasm_bindgen(js_name = verifyBase64)]
+    pub fn verify_base64(&self, address: &Address, message_b64: &str) -> Result<bool, String> {
+        let message = STANDARD.decode(message_b64).map_err(|_| "The message is not valid base64".to_string())?;
+        Ok(self.verify(address, &message))
+    }
+
+    /// Verify a signature produced by `PrivateKey.signAddresses` over an ordered list of addresses.
+    /// The list must be in the same order as when it was signed
+    ///
+    /// @param {Address} signer Address of the account which signed the list
+    /// @param {Address[]} addresses Ordered list of addresses which were signed
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyAddresses)]
+    pub fn verify_addresses(&self, signer: &Address, addresses: Vec<Address>) -> bool {
+        self.verify(signer, &Self::addresses_message(&addresses))
+    }
+
+    /// Verify a signature over a message consisting of a single field element, such as a short
+    /// byte message which packs into one field element. Aleo signatures do not sign a digest of the
+    /// message: every field element of the message is hashed together with the signer's keys to
+    /// form the Schnorr challenge, so there is no prehash which can stand in for a longer message.
+    /// Signatures over messages spanning several field elements must be verified with `verify`
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} field Field element which was signed, i.e. `123field`
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyField)]
+    pub fn verify_field(&self, address: &Address, field: &str) -> bool {
+        match FieldNative::from_str(field) {
+            Ok(field) => self.0.verify(address, &[field]),
+            Err(_) => false,
+        }
+    }
+
+    /// Verify a signature of a message against a list of allowed addresses, stopping at the first
+    /// address the signature is valid for
+    ///
+    /// @param {Address[]} addresses Addresses allowed to have signed the message
+    /// @param {Uint8Array} message Byte representation of the message to verify
+    /// @returns {number | undefined | Error} Index of the address that signed the message, or undefined if none did
+    #[wasm_bindgen(js_name = verifyAnyOf)]
+    pub fn verify_any_of(&self, addresses: Vec<Address>, message: &[u8]) -> Result<Option<usize>, String> {
+        if addresses.is_empty() {
+            return Err("No addresses were provided to verify the signature against".to_string());
+        }
+        Ok(addresses.iter().position(|address| self.verify(address, message)))
+    }
+
     /// Get a signature from a string representation of a signature
     ///
     /// @param {string} signature String representation of a signature
@@ -53,6 +174,60 @@ impl Signature {
         Self::from_str(signature).unwrap()
     }
 
+    /// Get a signature from either its current string representation (`sign1...`) or the legacy
+    /// representation used by earlier SDKs, which is the hex encoding of the little-endian bytes of
+    /// the signature with an optional `0x` prefix. Legacy signatures are normalized to the current
+    /// representation
+    ///
+    /// @param {string} signature Current or legacy string representation of a signature
+    /// @returns {Signature | Error} Signature
+    #[wasm_bindgen(js_name = fromStringCompat)]
+    pub fn from_string_compat(signature: &str) -> Result<Signature, String> {
+        let signature = signature.trim();
+        if let Ok(signature) = Self::from_str(signature) {
+            return Ok(signature);
+        }
+        let bytes = hex::decode(signature.strip_prefix("0x").unwrap_or(signature))
+            .map_err(|_| "The signature is neither a current nor a legacy signature string".to_string())?;
+        Ok(Self(SignatureNative::from_bytes_le(&bytes).map_err(|_| "The legacy signature is invalid".to_string())?))
+    }
+
+    /// Attempt to convert a generic 64 byte signature (such as an Ed25519 signature) into an Aleo
+    /// signature. This is not possible: Aleo signatures are Schnorr signatures over the Edwards
+    /// BLS12-377 curve which consist of a challenge, a response, and the signer's compute key, and
+    /// are verified using a Poseidon hash. A 64 byte signature lacks the compute key and is produced
+    /// over a different curve and hash, so an error is always returned explaining why
+    ///
+    /// @param {Uint8Array} bytes Generic 64 byte signature
+    /// @returns {Error} Error describing why the signature cannot be converted
+    #[wasm_bindgen(js_name = fromRaw64)]
+    pub fn from_raw_64(bytes: &[u8]) -> Result<Signature, String> {
+        if bytes.len() != 64 {
+            return Err(format!("Expected a 64 byte signature, found {} bytes", bytes.len()));
+        }
+        Err("64 byte signatures are not compatible with Aleo signatures, which are Schnorr signatures over the \
+             Edwards BLS12-377 curve that also contain the signer's compute key"
+            .to_string())
+    }
+
+    /// Get a signature from its byte representation
+    ///
+    /// @param {Uint8Array} bytes Byte representation of a signature produced by `toBytes`
+    /// @returns {Signature | Error} Signature
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, String> {
+        SignatureNative::from_bytes_le(bytes).map(Self).map_err(|_| "Invalid signature bytes".to_string())
+    }
+
+    /// Get the byte representation of a signature, which is more compact than its string
+    /// representation for storage or transmission
+    ///
+    /// @returns {Uint8Array} Byte representation of the signature
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes_le().unwrap()
+    }
+
     /// Get a string representation of a signature
     ///
     /// @returns {string} String representation of a signature
@@ -60,13 +235,191 @@ impl Signature {
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
+
+    /// Get the signature in the form consumed by Aleo program functions. Aleo programs take
+    /// signatures as `signature` literals (i.e. `input r0 as signature.private;`) rather than as a
+    /// struct of their components, so the result can be passed directly as a function input
+    ///
+    /// @returns {string} Signature literal suitable for use as a function input
+    #[wasm_bindgen(js_name = toProgramInput)]
+    pub fn to_program_input(&self) -> String {
+        PlaintextNative::from(LiteralNative::Signature(Box::new(self.0))).to_string()
+    }
+
+    /// Get a compact JWT-like token of the form `base64url(header).base64url(claims).base64url(signature)`
+    /// where the claims contain the address of the signer and the message that was signed
+    ///
+    /// @param {Address} address The address that signed the message
+    /// @param {Uint8Array} message Byte representation of the message that was signed
+    /// @returns {string} JWT-like token containing the signature
+    #[wasm_bindgen(js_name = toJwtLike)]
+    pub fn to_jwt_like(&self, address: &Address, message: &[u8]) -> String {
+        let claims = serde_json::json!({
+            "address": address.to_string(),
+            "message": URL_SAFE_NO_PAD.encode(message),
+        });
+        format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(JWT_LIKE_HEADER),
+            URL_SAFE_NO_PAD.encode(claims.to_string()),
+            URL_SAFE_NO_PAD.encode(self.0.to_bytes_le().unwrap())
+        )
+    }
+
+    /// Verify a JWT-like token produced by `toJwtLike`
+    ///
+    /// @param {string} token JWT-like token containing the signature
+    /// @returns {boolean | Error} True if the signature in the token is valid for the claims, false
+    /// otherwise. An error is returned if the token is malformed
+    #[wasm_bindgen(js_name = verifyJwtLike)]
+    pub fn verify_jwt_like(token: &str) -> Result<bool, String> {
+        let segments = token.split('.').collect::<Vec<&str>>();
+        if segments.len() != 3 {
+            return Err("The token must contain a header, claims, and signature".to_string());
+        }
+        let decode = |segment: &str, name: &str| {
+            URL_SAFE_NO_PAD.decode(segment).map_err(|_| format!("The token {name} is not valid base64url"))
+        };
+        if decode(segments[0], "header")? != JWT_LIKE_HEADER.as_bytes() {
+            return Err("The token header is not an Aleo signature header".to_string());
+        }
+        let claims: serde_json::Value = serde_json::from_slice(&decode(segments[1], "claims")?)
+            .map_err(|_| "The token claims are not valid JSON".to_string())?;
+        let address =
+            claims["address"].as_str().ok_or_else(|| "The token claims do not contain an address".to_string())?;
+        let address = Address::from_str(address).map_err(|_| "The token claims contain an invalid address")?;
+        let message =
+            claims["message"].as_str().ok_or_else(|| "The token claims do not contain a message".to_string())?;
+        let message = decode(message, "message")?;
+        let signature = SignatureNative::from_bytes_le(&decode(segments[2], "signature")?)
+            .map_err(|_| "The token signature is invalid".to_string())?;
+        Ok(signature.verify_bytes(&address, &message))
+    }
+}
+
+/// Verify a batch of signatures encoded as a JSON array of objects of the form
+/// `{ address, messageHex, signature }`. Entries with invalid addresses, messages, or signatures
+/// are reported as invalid rather than failing the whole batch
+///
+/// @param {string} json JSON array of address, hex encoded message, and signature strings
+/// @returns {Array | Error} Array of booleans indicating whether each signature is valid. An error is
+/// returned only if the JSON is malformed
+#[wasm_bindgen(js_name = "verifyBatchJson")]
+pub fn verify_batch_json(json: &str) -> Result<Array, String> {
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|_| "The batch must be a JSON array of signature entries".to_string())?;
+    let results = Array::new_with_length(entries.len() as u32);
+    for (index, entry) in entries.iter().enumerate() {
+        results.set(index as u32, verify_batch_entry(entry).unwrap_or(false).into());
+    }
+    Ok(results)
+}
+
+// Verify a single entry of a JSON signature batch, returning None if the entry is malformed
+fn verify_batch_entry(entry: &serde_json::Value) -> Option<bool> {
+    let address = Address::from_str(entry["address"].as_str()?).ok()?;
+    let message = hex::decode(entry["messageHex"].as_str()?).ok()?;
+    let signature = Signature::from_str(entry["signature"].as_str()?).ok()?;
+    Some(signature.verify(&address, &message))
+}
+
+/// Verify a signature of a message where the address, message, and signature are all given as
+/// strings, such as in a stateless server endpoint
+///
+/// @param {string} address Address of the signer
+/// @param {string} message_hex Hex encoded message which was signed
+/// @param {string} signature String representation of the signature
+/// @returns {boolean | Error} True if the signature is valid, false otherwise. An error naming the
+/// malformed argument is returned if any argument cannot be parsed
+#[wasm_bindgen(js_name = "verifyAllStrings")]
+pub fn verify_all_strings(address: &str, message_hex: &str, signature: &str) -> Result<bool, String> {
+    let message = hex::decode(message_hex).map_err(|_| "The message is not valid hex".to_string())?;
+    verify_signature(address, &message, signature)
+}
+
+/// Verify a signature of a message given the address and signature as strings, such as when
+/// checking a signed login challenge
+///
+/// @param {string} address Address of the signer
+/// @param {Uint8Array} message Byte array representing the message which was signed
+/// @param {string} signature String representation of the signature
+/// @returns {boolean | Error} True if the signature is valid, false otherwise. An error is returned
+/// if the address or signature cannot be parsed
+#[wasm_bindgen(js_name = "verifySignature")]
+pub fn verify_signature(address: &str, message: &[u8], signature: &str) -> Result<bool, String> {
+    let address = Address::from_string(address).map_err(|error| format!("The address is invalid: {error}"))?;
+    let signature = Signature::from_str(signature).map_err(|_| "The signature is invalid".to_string())?;
+    Ok(signature.verify(&address, message))
+}
+
+/// Verify a proof of funds attestation produced by `PrivateKey.attestFunds`. This checks that the
+/// attestation was signed by the stated address over the stated commitment and minimum amount
+///
+/// @param {string} attestation JSON attestation of the form `{ address, commitment, min_amount, signature }`
+/// @returns {boolean | Error} True if the attestation is valid, false otherwise. An error is returned
+/// if the attestation is malformed
+#[wasm_bindgen(js_name = "verifyFundsAttestation")]
+pub fn verify_funds_attestation(attestation: &str) -> Result<bool, String> {
+    let attestation: serde_json::Value =
+        serde_json::from_str(attestation).map_err(|_| "The attestation is not valid JSON".to_string())?;
+    let field =
+        |name: &str| attestation[name].as_str().ok_or_else(|| format!("The attestation does not contain a {name}"));
+    let address = Address::from_str(field("address")?).map_err(|_| "The attestation address is invalid")?;
+    let commitment = field("commitment")?;
+    FieldNative::from_str(commitment).map_err(|_| "The attestation commitment is invalid")?;
+    let min_amount =
+        attestation["min_amount"].as_u64().ok_or_else(|| "The attestation minimum amount is invalid".to_string())?;
+    let signature = Signature::from_str(field("signature")?).map_err(|_| "The attestation signature is invalid")?;
+    Ok(signature.verify(&address, &Signature::funds_attestation_message(&address, commitment, min_amount)))
+}
+
+impl Signature {
+    /// Sign a message with a native private key using the shared signing random number generator
+    fn sign_native(private_key: &PrivateKeyNative, message: &[u8]) -> Self {
+        Self::sign_with_rng(private_key, message, &mut *SIGNING_RNG.lock().unwrap())
+    }
+
+    /// Sign a message with a native private key using the given random number generator
+    pub(crate) fn sign_with_rng<R: Rng + CryptoRng>(
+        private_key: &PrivateKeyNative,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Self {
+        Self(SignatureNative::sign_bytes(private_key, message, rng).unwrap())
+    }
+
+    /// Construct the message signed over an ordered list of addresses. Each address is encoded as its
+    /// little-endian bytes after the number of addresses, so the message depends on the order
+    pub(crate) fn addresses_message(addresses: &[Address]) -> Vec<u8> {
+        let mut message = b"aleo_address_list:".to_vec();
+        message.extend_from_slice(&(addresses.len() as u32).to_le_bytes());
+        for address in addresses {
+            message.extend_from_slice(&address.to_bytes_le().unwrap());
+        }
+        message
+    }
+
+    /// Construct the message signed within a proof of funds attestation
+    pub(crate) fn funds_attestation_message(address: &Address, commitment: &str, min_amount: u64) -> Vec<u8> {
+        format!("aleo_funds_attestation:{address}:{commitment}:{min_amount}").into_bytes()
+    }
+
+    /// Construct the message signed within a time-limited token. The payload is tagged and prefixed
+    /// by its length, so a signature over arbitrary bytes cannot be replayed as a token
+    pub(crate) fn token_message(payload: &[u8], expires_at: u64) -> Vec<u8> {
+        let mut message = b"aleo_token:".to_vec();
+        message.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        message.extend_from_slice(payload);
+        message.extend_from_slice(&expires_at.to_le_bytes());
+        message
+    }
 }
 
 impl FromStr for Signature {
     type Err = anyhow::Error;
 
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
-        Ok(Self(SignatureNative::from_str(signature).unwrap()))
+        Ok(Self(SignatureNative::from_str(signature)?))
     }
 }
 
@@ -87,6 +440,7 @@ impl Deref for Signature {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::account::sort_addresses;
 
     use rand::{rngs::StdRng, Rng, SeedableRng};
     use wasm_bindgen_test::*;
@@ -111,4 +465,324 @@ mod tests {
             assert!(!signature.verify(&private_key.to_address(), &bad_message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_base64() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let signature = Signature::sign(&private_key, b"hello world");
+
+        assert!(signature.verify_base64(&address, "aGVsbG8gd29ybGQ=").unwrap());
+        assert!(!signature.verify_base64(&address, "aGVsbG8gd29ybGQh").unwrap());
+
+        // Check malformed base64 is an error.
+        assert!(signature.verify_base64(&address, "not base64!").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_field() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let message = b"fits in one field";
+        let signature = Signature::sign(&private_key, message);
+
+        // Check verifying against the field the message is encoded as agrees with verify.
+        let field = FieldNative::from_bytes_le(&PrivateKey::signing_preimage(message)).unwrap().to_string();
+        assert!(signature.verify(&address, message));
+        assert!(signature.verify_field(&address, &field));
+
+        // Check a different field, a different address, and a malformed field are rejected.
+        assert!(!signature.verify_field(&address, "1field"));
+        assert!(!signature.verify_field(&PrivateKey::new().to_address(), &field));
+        assert!(!signature.verify_field(&address, "not a field"));
+
+        // Check a message spanning several field elements cannot be verified by any single field.
+        let long_message = [7u8; 64];
+        let long_signature = Signature::sign(&private_key, &long_message);
+        let preimage = PrivateKey::signing_preimage(&long_message);
+        assert!(preimage.len() > 32);
+        for chunk in preimage.chunks(32) {
+            let field = FieldNative::from_bytes_le(chunk).unwrap().to_string();
+            assert!(!long_signature.verify_field(&address, &field));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_message() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let message = "Sign in to Aleo 🔐 nonce: 42 ✅";
+
+        let signature = private_key.sign_message(message);
+        assert!(signature.verify_message(&address, message));
+        assert!(signature.verify(&address, message.as_bytes()));
+        assert!(!signature.verify_message(&PrivateKey::new().to_address(), message));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_modified_message() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+
+        let signature = private_key.sign_message("Sign in to Aleo 🔐");
+        assert!(!signature.verify_message(&address, "Sign in to Aleo 🔓"));
+        assert!(!signature.verify_message(&address, "Sign in to Aleo"));
+        assert!(!signature.verify_message(&address, "sign in to Aleo 🔐"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_addresses() {
+        let private_key = PrivateKey::new();
+        let signer = private_key.to_address();
+        let approvers = (0..3).map(|_| PrivateKey::new().to_address()).collect::<Vec<_>>();
+
+        let signature = private_key.sign_addresses(approvers.clone());
+        assert!(signature.verify_addresses(&signer, approvers.clone()));
+        assert!(!signature.verify_addresses(&PrivateKey::new().to_address(), approvers.clone()));
+        assert!(!signature.verify_addresses(&signer, approvers[..2].to_vec()));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_addresses_order_sensitive() {
+        let private_key = PrivateKey::new();
+        let signer = private_key.to_address();
+        let approvers = (0..3).map(|_| PrivateKey::new().to_address()).collect::<Vec<_>>();
+        let mut reordered = approvers.clone();
+        reordered.reverse();
+
+        // Check reordering the list invalidates the signature.
+        let signature = private_key.sign_addresses(approvers.clone());
+        assert!(!signature.verify_addresses(&signer, reordered.clone()));
+
+        // Check sorting both lists first makes the signature independent of the original order.
+        let signature = private_key.sign_addresses(sort_addresses(approvers));
+        assert!(signature.verify_addresses(&signer, sort_addresses(reordered)));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_any_of() {
+        let private_key = PrivateKey::new();
+        let message = b"allowlisted action";
+        let signature = Signature::sign(&private_key, message);
+
+        // Check the index of the signer is found in the middle of the list.
+        let allowed = vec![PrivateKey::new().to_address(), private_key.to_address(), PrivateKey::new().to_address()];
+        assert_eq!(signature.verify_any_of(allowed, message).unwrap(), Some(1));
+
+        // Check no index is found when the signer is absent.
+        let others = vec![PrivateKey::new().to_address(), PrivateKey::new().to_address()];
+        assert_eq!(signature.verify_any_of(others, message).unwrap(), None);
+
+        // Check an empty list is reported as an error.
+        assert!(signature.verify_any_of(vec![], message).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string_compat() {
+        let private_key = PrivateKey::new();
+        let message = b"signed by an earlier SDK";
+        let signature = Signature::sign(&private_key, message);
+        let legacy = hex::encode(signature.to_bytes_le().unwrap());
+
+        // Check the legacy form is normalized to the current form and still verifies.
+        for legacy in [legacy.clone(), format!("0x{legacy}")] {
+            let parsed = Signature::from_string_compat(&legacy).unwrap();
+            assert_eq!(parsed.to_string(), signature.to_string());
+            assert!(parsed.verify(&private_key.to_address(), message));
+        }
+
+        // Check the current form is still accepted.
+        assert_eq!(Signature::from_string_compat(&signature.to_string()).unwrap().to_string(), signature.to_string());
+
+        // Check malformed signatures are rejected.
+        assert!(Signature::from_string_compat("sign1invalid").is_err());
+        assert!(Signature::from_string_compat(&legacy[..legacy.len() - 2]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_address() {
+        let private_key = PrivateKey::new();
+        let signature = Signature::sign(&private_key, b"who signed this");
+
+        // Check the signer address is recovered and the signature verifies against it.
+        assert_eq!(signature.to_address(), private_key.to_address());
+        assert!(signature.verify(&signature.to_address(), b"who signed this"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_after_reseed() {
+        let private_key = PrivateKey::new();
+        let before = private_key.sign(b"reseed");
+        Signature::reseed_rng();
+        let after = private_key.sign(b"reseed");
+
+        // Check signatures made before and after the reseed both verify and are randomized.
+        assert!(before.verify(&private_key.to_address(), b"reseed"));
+        assert!(after.verify(&private_key.to_address(), b"reseed"));
+        assert_ne!(before.to_string(), after.to_string());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bytes_round_trip() {
+        let private_key = PrivateKey::new();
+        let signature = private_key.sign(b"persist me");
+        let bytes = signature.to_bytes();
+        assert!(bytes.len() < signature.to_string().len());
+
+        let recovered = Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(recovered.to_string(), signature.to_string());
+        assert!(recovered.verify(&private_key.to_address(), b"persist me"));
+
+        // Check truncated bytes are rejected.
+        assert!(Signature::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Signature::from_bytes(&[]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_raw_64() {
+        // Check a well-formed 64 byte signature is rejected as incompatible.
+        let error = Signature::from_raw_64(&[7u8; 64]).err().unwrap();
+        assert!(error.contains("not compatible"));
+
+        // Check a wrong length input is rejected as malformed.
+        let error = Signature::from_raw_64(&[7u8; 63]).err().unwrap();
+        assert_eq!(error, "Expected a 64 byte signature, found 63 bytes");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_with_ciphertext() {
+        let private_key = PrivateKey::new();
+        let ciphertext = private_key.to_ciphertext("mypassword").unwrap();
+        let message = b"sign without exposing the key";
+
+        // Check the signature matches one produced after decrypting manually. Signatures are
+        // randomized, so compare the signer compute keys rather than the signatures themselves.
+        let signature = Signature::sign_with_ciphertext(&ciphertext, "mypassword", message).unwrap();
+        let decrypted = PrivateKey::from_private_key_ciphertext(&ciphertext, "mypassword").unwrap();
+        let expected = Signature::sign(&decrypted, message);
+        assert!(signature.verify(&decrypted.to_address(), message));
+        assert!(expected.verify(&decrypted.to_address(), message));
+        assert_eq!(signature.compute_key(), expected.compute_key());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_with_ciphertext_wrong_password() {
+        let ciphertext = PrivateKey::new().to_ciphertext("mypassword").unwrap();
+        assert!(Signature::sign_with_ciphertext(&ciphertext, "wrong_password", b"message").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_batch_json() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let signature = Signature::sign(&private_key, b"message").to_string();
+        let other_address = PrivateKey::new().to_address().to_string();
+        let json = serde_json::json!([
+            { "address": address, "messageHex": hex::encode(b"message"), "signature": signature },
+            { "address": other_address, "messageHex": hex::encode(b"message"), "signature": signature },
+            { "address": address, "messageHex": hex::encode(b"tampered"), "signature": signature },
+            { "address": address, "messageHex": "not hex", "signature": signature },
+            { "address": "aleo1invalid", "messageHex": hex::encode(b"message"), "signature": signature },
+            { "address": address, "messageHex": hex::encode(b"message"), "signature": "sign1invalid" },
+            { "address": address },
+        ]);
+
+        let results = verify_batch_json(&json.to_string()).unwrap();
+        let results = results.iter().map(|result| result.as_bool().unwrap()).collect::<Vec<bool>>();
+        assert_eq!(results, vec![true, false, false, false, false, false, false]);
+
+        // Check malformed JSON is reported as an error.
+        assert!(verify_batch_json("not json").is_err());
+        assert!(verify_batch_json(r#"{ "address": "aleo1" }"#).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_all_strings() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let message_hex = hex::encode(b"message");
+        let signature = Signature::sign(&private_key, b"message").to_string();
+
+        assert!(verify_all_strings(&address, &message_hex, &signature).unwrap());
+        assert!(!verify_all_strings(&address, &hex::encode(b"tampered"), &signature).unwrap());
+        let other_address = PrivateKey::new().to_address().to_string();
+        assert!(!verify_all_strings(&other_address, &message_hex, &signature).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_all_strings_malformed() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let message_hex = hex::encode(b"message");
+        let signature = Signature::sign(&private_key, b"message").to_string();
+
+        let error = verify_all_strings("aleo1invalid", &message_hex, &signature).unwrap_err();
+        assert!(error.starts_with("The address is invalid"));
+        let error = verify_all_strings(&address, "not hex", &signature).unwrap_err();
+        assert_eq!(error, "The message is not valid hex");
+        let error = verify_all_strings(&address, &message_hex, "sign1invalid").unwrap_err();
+        assert_eq!(error, "The signature is invalid");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_verify_signature() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address().to_string();
+        let signature = Signature::sign(&private_key, b"login challenge").to_string();
+
+        assert!(verify_signature(&address, b"login challenge", &signature).unwrap());
+        assert!(!verify_signature(&address, b"tampered challenge", &signature).unwrap());
+
+        // Check malformed addresses and signatures are reported as errors.
+        let error = verify_signature("aleo1invalid", b"login challenge", &signature).unwrap_err();
+        assert!(error.starts_with("The address is invalid"));
+        let error = verify_signature(&address, b"login challenge", "sign1invalid").unwrap_err();
+        assert_eq!(error, "The signature is invalid");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_program_input() {
+        let private_key = PrivateKey::new();
+        let signature = Signature::sign(&private_key, b"program input");
+        let input = signature.to_program_input();
+
+        // Check the input parses as a signature literal containing the original signature.
+        match PlaintextNative::from_str(&input).unwrap() {
+            PlaintextNative::Literal(LiteralNative::Signature(parsed), ..) => assert_eq!(*parsed, *signature),
+            _ => panic!("The program input was not a signature literal"),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_jwt_like_round_trip() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let message = b"login challenge";
+        let signature = Signature::sign(&private_key, message);
+
+        let token = signature.to_jwt_like(&address, message);
+        assert_eq!(token.split('.').count(), 3);
+        assert!(Signature::verify_jwt_like(&token).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_jwt_like_tampered_claims() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let signature = Signature::sign(&private_key, b"login challenge");
+        let token = signature.to_jwt_like(&address, b"login challenge");
+        let segments = token.split('.').collect::<Vec<&str>>();
+
+        // Replace the claims with a different message.
+        let claims = serde_json::json!({
+            "address": address.to_string(),
+            "message": URL_SAFE_NO_PAD.encode(b"transfer everything"),
+        });
+        let tampered = format!("{}.{}.{}", segments[0], URL_SAFE_NO_PAD.encode(claims.to_string()), segments[2]);
+        assert!(!Signature::verify_jwt_like(&tampered).unwrap());
+
+        // Ensure malformed claims are reported as an error.
+        let malformed = format!("{}.{}.{}", segments[0], URL_SAFE_NO_PAD.encode("not json"), segments[2]);
+        assert!(Signature::verify_jwt_like(&malformed).is_err());
+    }
 }
@@ -14,13 +14,71 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{Address, PrivateKey};
-use crate::record::RecordCiphertext;
+use super::{js_error_with_code, sanitize_key_string, AccountError, Address, PrivateKey};
+use crate::{record::RecordCiphertext, Credits};
 
-use crate::types::native::ViewKeyNative;
+use crate::types::native::{FromBytes, PrivateKeyNative, ToBytes, ViewKeyNative, Zero};
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+use hkdf::Hkdf;
+use sha2::Sha256;
 use wasm_bindgen::prelude::*;
 
+/// Length in bytes of a shared secret derived with `ViewKey::shared_secret`
+pub const SHARED_SECRET_LEN: usize = 32;
+
+/// Prefix of the string representation of a view key
+const VIEW_KEY_PREFIX: &str = "AViewKey1";
+
+/// Length in bytes of the byte representation of a view key
+const VIEW_KEY_BYTES: usize = 32;
+
+/// Type of the account descriptors produced by `ViewKey::to_watch_only_json`
+const WATCH_ONLY_TYPE: &str = "watch-only";
+
+/// Reasons decrypting a record ciphertext with a view key can fail. In JS the error is thrown as an
+/// `Error` with a `code` property of either "NOT_OWNER" or "MALFORMED_CIPHERTEXT", so scanning
+/// clients can skip records they do not own and report corrupt ones
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecryptError {
+    /// The record is well formed but is not owned by the view key
+    NotOwner,
+    /// The ciphertext could not be parsed or decrypted
+    MalformedCiphertext(String),
+}
+
+impl DecryptError {
+    /// Get the error code surfaced to JS for the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotOwner => "NOT_OWNER",
+            Self::MalformedCiphertext(_) => "MALFORMED_CIPHERTEXT",
+        }
+    }
+}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotOwner => write!(f, "View key is not the owner of the record"),
+            Self::MalformedCiphertext(error) => write!(f, "Malformed record ciphertext: {error}"),
+        }
+    }
+}
+
+impl From<DecryptError> for JsValue {
+    fn from(error: DecryptError) -> Self {
+        js_error_with_code(&error.to_string(), error.code())
+    }
+}
+
+/// View key of an Aleo account, used to decrypt records owned by the account.
+///
+/// A view key corresponds to exactly one address (`address = G * view_key`). Aleo does not support
+/// diversified addresses or sub-addresses: a record is only detectable by the view key whose address
+/// it was encrypted to, and only spendable by the private key behind that same address. Deriving
+/// additional receiving addresses from a view key is therefore not possible, and there are no
+/// sub-address indices to allocate. Wallets needing several receiving addresses must manage a
+/// separate account (private key, view key, and address) for each one.
 #[wasm_bindgen]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ViewKey(ViewKeyNative);
@@ -35,12 +93,50 @@ impl ViewKey {
         Self(ViewKeyNative::try_from(**private_key).unwrap())
     }
 
-    /// Create a new view key from a string representation of a view key
+    /// Create a new view key from a string representation of a view key. View keys which are
+    /// structurally invalid, out of range, or not in their canonical encoding are rejected, since a
+    /// modified view key does not correspond to any account
     ///
     /// @param {string} view_key String representation of a view key
-    /// @returns {ViewKey} View key
-    pub fn from_string(view_key: &str) -> Self {
-        Self::from_str(view_key).unwrap()
+    /// @returns {ViewKey | Error} View key
+    pub fn from_string(view_key: &str) -> Result<ViewKey, String> {
+        let view_key = sanitize_key_string(view_key);
+        if !view_key.starts_with(VIEW_KEY_PREFIX) {
+            return Err(format!("The view key must begin with {VIEW_KEY_PREFIX}"));
+        }
+        let parsed = Self::from_str(&view_key)
+            .map_err(|_| "The view key is structurally invalid or its scalar is out of range".to_string())?;
+        if parsed.0.is_zero() {
+            return Err("The view key scalar is out of range".to_string());
+        }
+        if parsed.to_string() != view_key {
+            return Err("The view key is not in its canonical encoding".to_string());
+        }
+        Ok(parsed)
+    }
+
+    /// Get a view key from its byte representation
+    ///
+    /// @param {Uint8Array} bytes 32 byte representation of a view key produced by `toBytes`
+    /// @returns {ViewKey | Error} View key, or an error if the bytes are not a valid view key
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<ViewKey, String> {
+        if bytes.len() != VIEW_KEY_BYTES {
+            return Err(format!("A view key must be {VIEW_KEY_BYTES} bytes, found {} bytes", bytes.len()));
+        }
+        let view_key = ViewKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid view key bytes".to_string())?;
+        if view_key.is_zero() {
+            return Err("The view key scalar is out of range".to_string());
+        }
+        Ok(Self(view_key))
+    }
+
+    /// Get the 32 byte representation of the view key
+    ///
+    /// @returns {Uint8Array} Byte representation of the view key
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes_le().unwrap()
     }
 
     /// Get a string representation of a view key
@@ -58,24 +154,139 @@ impl ViewKey {
         Address::from_view_key(self)
     }
 
+    /// Get a watch-only account descriptor for the view key of the form
+    /// `{ viewKey, address, type: "watch-only" }`, which wallets can import to track an account
+    /// without being able to spend from it
+    ///
+    /// @returns {string} JSON watch-only account descriptor
+    #[wasm_bindgen(js_name = toWatchOnlyJson)]
+    pub fn to_watch_only_json(&self) -> String {
+        serde_json::json!({
+            "viewKey": self.to_string(),
+            "address": self.to_address().to_string(),
+            "type": WATCH_ONLY_TYPE,
+        })
+        .to_string()
+    }
+
+    /// Load the view key from a watch-only account descriptor produced by `toWatchOnlyJson`. The
+    /// descriptor is rejected if it is not a watch-only descriptor or if the address does not
+    /// correspond to the view key
+    ///
+    /// @param {string} descriptor JSON watch-only account descriptor
+    /// @returns {ViewKey | Error} View key of the watch-only account
+    #[wasm_bindgen(js_name = fromWatchOnlyJson)]
+    pub fn from_watch_only_json(descriptor: &str) -> Result<ViewKey, String> {
+        let descriptor: serde_json::Value =
+            serde_json::from_str(descriptor).map_err(|_| "The descriptor is not valid JSON".to_string())?;
+        let field =
+            |name: &str| descriptor[name].as_str().ok_or_else(|| format!("The descriptor does not contain a {name}"));
+        if field("type")? != WATCH_ONLY_TYPE {
+            return Err(format!("The descriptor type must be {WATCH_ONLY_TYPE}"));
+        }
+        let view_key = Self::from_string(field("viewKey")?)?;
+        let address = Address::from_string(field("address")?)?;
+        if view_key.to_address() != address {
+            return Err("The descriptor address does not match the view key".to_string());
+        }
+        Ok(view_key)
+    }
+
+    /// Derive a shared secret with another account using elliptic curve Diffie-Hellman. Both
+    /// parties derive the same secret from their own view key and the other party's address. The
+    /// x-coordinate of the shared point is passed through HKDF-SHA256, so the secret is uniformly
+    /// random and can be used directly as a symmetric key
+    ///
+    /// @param {Address} peer Address of the other party
+    /// @returns {Uint8Array | Error} Shared secret of exactly `sharedSecretLen` bytes
+    #[wasm_bindgen(js_name = sharedSecret)]
+    pub fn shared_secret(&self, peer: &Address) -> Result<Vec<u8>, String> {
+        let shared_point = *peer.to_group() * *self.0;
+        let shared_x = shared_point.to_x_coordinate().to_bytes_le().map_err(|e| e.to_string())?;
+        let mut secret = [0u8; SHARED_SECRET_LEN];
+        Hkdf::<Sha256>::new(None, &shared_x)
+            .expand(b"AleoSharedSecret", &mut secret)
+            .map_err(|_| "Failed to derive the shared secret".to_string())?;
+        Ok(secret.to_vec())
+    }
+
+    /// Get the length in bytes of shared secrets derived with `sharedSecret`
+    ///
+    /// @returns {number} Length of a shared secret in bytes
+    #[wasm_bindgen(js_name = sharedSecretLen)]
+    pub fn shared_secret_len() -> usize {
+        SHARED_SECRET_LEN
+    }
+
+    /// Get the amount of microcredits in each record ciphertext owned by the view key. Records which
+    /// are not owned by the view key or which do not contain microcredits are skipped
+    ///
+    /// @param {string[]} ciphertexts String representations of record ciphertexts
+    /// @returns {BigUint64Array | Error} Amount of microcredits in each owned credits record
+    #[wasm_bindgen(js_name = scanValues)]
+    pub fn scan_values(&self, ciphertexts: Vec<String>) -> Result<Vec<u64>, String> {
+        let mut values = vec![];
+        for ciphertext in ciphertexts {
+            let ciphertext = RecordCiphertext::from_string(&ciphertext)?;
+            if !ciphertext.is_owner(self) {
+                continue;
+            }
+            if let Ok(microcredits) = ciphertext.decrypt(self)?.deref().microcredits() {
+                values.push(microcredits);
+            }
+        }
+        Ok(values)
+    }
+
+    /// Get the total amount of microcredits in the record ciphertexts owned by the view key, which
+    /// is the balance of the records held by the account. Records are selected as in `scanValues`
+    ///
+    /// @param {string[]} ciphertexts String representations of record ciphertexts
+    /// @returns {bigint | Error} Total amount of microcredits in the owned credits records, or an
+    /// error if a ciphertext is malformed or the total overflows a u64
+    #[wasm_bindgen(js_name = totalOwnedMicrocredits)]
+    pub fn total_owned_microcredits(&self, ciphertexts: Vec<String>) -> Result<u64, String> {
+        self.scan_values(ciphertexts)?.into_iter().try_fold(0u64, |total, value| {
+            total.checked_add(value).ok_or_else(|| "The total amount of microcredits overflows a u64".to_string())
+        })
+    }
+
     /// Decrypt a record ciphertext with a view key
     ///
     /// @param {string} ciphertext String representation of a record ciphertext
-    /// @returns {string} String representation of a record plaintext
-    pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
-        let ciphertext = RecordCiphertext::from_str(ciphertext).map_err(|error| error.to_string())?;
-        match ciphertext.decrypt(self) {
-            Ok(plaintext) => Ok(plaintext.to_string()),
-            Err(error) => Err(error),
+    /// @returns {string | Error} String representation of a record plaintext, or an error with a
+    /// `code` of "NOT_OWNER" if the view key does not own the record or "MALFORMED_CIPHERTEXT" if
+    /// the ciphertext is corrupt
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, DecryptError> {
+        let ciphertext = RecordCiphertext::from_str(ciphertext)
+            .map_err(|error| DecryptError::MalformedCiphertext(error.to_string()))?;
+        if !ciphertext.is_owner(self) {
+            return Err(DecryptError::NotOwner);
         }
+        let plaintext =
+            ciphertext.deref().decrypt(self).map_err(|error| DecryptError::MalformedCiphertext(error.to_string()))?;
+        Ok(plaintext.to_string())
     }
 }
 
+/// Derive the view key of a private key string in a single call, without constructing a
+/// `PrivateKey` object
+///
+/// @param {string} private_key String representation of a private key
+/// @returns {string | Error} String representation of the view key of the private key
+#[wasm_bindgen(js_name = "viewKeyFromPrivateKeyString")]
+pub fn view_key_from_private_key_string(private_key: &str) -> Result<String, String> {
+    let private_key = PrivateKeyNative::from_str(&sanitize_key_string(private_key))
+        .map_err(|_| AccountError::InvalidPrivateKey.to_string())?;
+    let view_key = ViewKeyNative::try_from(private_key).map_err(|_| "Failed to derive the view key".to_string())?;
+    Ok(view_key.to_string())
+}
+
 impl FromStr for ViewKey {
     type Err = anyhow::Error;
 
     fn from_str(view_key: &str) -> Result<Self, Self::Err> {
-        Ok(Self(ViewKeyNative::from_str(view_key)?))
+        Ok(Self(ViewKeyNative::from_str(&sanitize_key_string(view_key))?))
     }
 }
 
@@ -96,7 +307,10 @@ impl Deref for ViewKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{record::encrypt_record, types::native::RecordPlaintextNative};
 
+    use js_sys::Reflect;
+    use wasm_bindgen::JsCast;
     use wasm_bindgen_test::*;
 
     const RECORD_PLAINTEXT: &str = r"{
@@ -117,9 +331,135 @@ mod tests {
         assert_eq!(given_view_key, view_key.to_string());
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_view_key_from_private_key_string() {
+        let view_key = view_key_from_private_key_string("APrivateKey1zkp4RyQ8Utj7aRcJgPQGEok8RMzWwUZzBhhgX6rhmBT8dcP");
+        assert_eq!(view_key.unwrap(), "AViewKey1i3fn5SECcVBtQMCVtTPSvdApoMYmg3ToJfNDfgHJAuoD");
+
+        // Check the derivation matches the view key of a private key object.
+        let private_key = PrivateKey::new();
+        assert_eq!(
+            view_key_from_private_key_string(&private_key.to_string()).unwrap(),
+            private_key.to_view_key().to_string()
+        );
+        assert!(view_key_from_private_key_string("APrivateKey1invalid").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        assert_eq!(view_key.to_string(), OWNER_VIEW_KEY);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string_invalid() {
+        // Check keys with the wrong prefix, a mangled body, or the wrong length are rejected.
+        assert!(ViewKey::from_string(&OWNER_VIEW_KEY.replace("AViewKey1", "APrivateKey1")).is_err());
+        assert!(ViewKey::from_string(&OWNER_VIEW_KEY.replace('c', "0")).is_err());
+        assert!(ViewKey::from_string(&OWNER_VIEW_KEY[..OWNER_VIEW_KEY.len() - 4]).is_err());
+        assert!(ViewKey::from_string(&format!("{OWNER_VIEW_KEY}111")).is_err());
+        assert!(ViewKey::from_string("AViewKey1").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_bytes_round_trip() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let bytes = view_key.to_bytes();
+        assert_eq!(bytes.len(), VIEW_KEY_BYTES);
+        assert_eq!(ViewKey::from_bytes(&bytes).unwrap(), view_key);
+
+        // Check the wrong length and the zero scalar are rejected.
+        assert!(ViewKey::from_bytes(&bytes[..VIEW_KEY_BYTES - 1]).is_err());
+        assert!(ViewKey::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(ViewKey::from_bytes(&[0u8; VIEW_KEY_BYTES]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_to_address() {
+        let private_key = PrivateKey::new();
+        let view_key = ViewKey::from_bytes(&private_key.to_view_key().to_bytes()).unwrap();
+        assert_eq!(view_key.to_address(), private_key.to_address());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_watch_only_json_round_trip() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let descriptor = view_key.to_watch_only_json();
+        let json: serde_json::Value = serde_json::from_str(&descriptor).unwrap();
+        assert_eq!(json["viewKey"], OWNER_VIEW_KEY);
+        assert_eq!(json["address"], view_key.to_address().to_string());
+        assert_eq!(json["type"], "watch-only");
+        assert_eq!(ViewKey::from_watch_only_json(&descriptor).unwrap(), view_key);
+
+        // Check invalid descriptors are rejected.
+        let other_address = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap().to_address().to_string();
+        assert!(ViewKey::from_watch_only_json(&descriptor.replace("watch-only", "full")).is_err());
+        assert!(
+            ViewKey::from_watch_only_json(&descriptor.replace(&view_key.to_address().to_string(), &other_address))
+                .is_err()
+        );
+        assert!(ViewKey::from_watch_only_json(r#"{ "type": "watch-only" }"#).is_err());
+        assert!(ViewKey::from_watch_only_json("not json").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_shared_secret() {
+        let alice = PrivateKey::new().to_view_key();
+        let bob = PrivateKey::new().to_view_key();
+        let alice_secret = alice.shared_secret(&bob.to_address()).unwrap();
+        let bob_secret = bob.shared_secret(&alice.to_address()).unwrap();
+
+        // Check both parties derive the same secret of the advertised length.
+        assert_eq!(alice_secret, bob_secret);
+        assert_eq!(alice_secret.len(), ViewKey::shared_secret_len());
+
+        // Check the secret is derived from, rather than equal to, the x-coordinate of the shared point.
+        let shared_x = (*bob.to_address().to_group() * *alice.0).to_x_coordinate().to_bytes_le().unwrap();
+        assert_ne!(alice_secret, shared_x);
+
+        // Check a different peer produces a different secret.
+        let carol = PrivateKey::new().to_view_key();
+        assert_ne!(alice_secret, alice.shared_secret(&carol.to_address()).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_scan_values() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let other_address = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap().to_address();
+        let ciphertexts = vec![
+            OWNER_CIPHERTEXT.to_string(),
+            encrypt_record(&other_address, "microcredits: 5u64.private").to_string(),
+            encrypt_record(&view_key.to_address(), "amount: 7u64.private").to_string(),
+            encrypt_record(&view_key.to_address(), "microcredits: 3u64.private").to_string(),
+        ];
+        assert_eq!(view_key.scan_values(ciphertexts).unwrap(), vec![1500000000000000, 3]);
+
+        // Check malformed ciphertexts are reported as an error.
+        assert!(view_key.scan_values(vec!["garbage".to_string()]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_total_owned_microcredits() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let other_address = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap().to_address();
+        let ciphertexts = vec![
+            OWNER_CIPHERTEXT.to_string(),
+            encrypt_record(&other_address, "microcredits: 5u64.private").to_string(),
+            encrypt_record(&view_key.to_address(), "amount: 7u64.private").to_string(),
+            encrypt_record(&view_key.to_address(), "microcredits: 3u64.private").to_string(),
+        ];
+        assert_eq!(view_key.total_owned_microcredits(ciphertexts).unwrap(), 1500000000000003);
+        assert_eq!(view_key.total_owned_microcredits(vec![]).unwrap(), 0);
+
+        // Check an overflowing total and malformed ciphertexts are reported as errors.
+        let max = encrypt_record(&view_key.to_address(), &format!("microcredits: {}u64.private", u64::MAX)).to_string();
+        assert!(view_key.total_owned_microcredits(vec![max, OWNER_CIPHERTEXT.to_string()]).is_err());
+        assert!(view_key.total_owned_microcredits(vec!["garbage".to_string()]).is_err());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_decrypt_success() {
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         let plaintext = view_key.decrypt(OWNER_CIPHERTEXT);
         plaintext.clone().unwrap();
         assert!(plaintext.is_ok());
@@ -129,8 +469,49 @@ mod tests {
     #[wasm_bindgen_test]
     pub fn test_decrypt_fails() {
         let ciphertext = RecordCiphertext::from_str(OWNER_CIPHERTEXT).map_err(|error| error.to_string()).unwrap();
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         let plaintext = ciphertext.decrypt(&incorrect_view_key);
         assert!(plaintext.is_err());
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_from_private_key() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkp4RyQ8Utj7aRcJgPQGEok8RMzWwUZzBhhgX6rhmBT8dcP").unwrap();
+        let view_key = private_key.to_view_key();
+        let ciphertext = encrypt_record(&private_key.to_address(), "microcredits: 42u64.private").to_string();
+        let plaintext = RecordPlaintextNative::from_str(&view_key.decrypt(&ciphertext).unwrap()).unwrap();
+        assert_eq!(**plaintext.owner(), *private_key.to_address());
+        assert_eq!(plaintext.microcredits().unwrap(), 42);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_not_owner() {
+        let view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
+        let error = view_key.decrypt(OWNER_CIPHERTEXT).unwrap_err();
+        assert_eq!(error, DecryptError::NotOwner);
+        assert_eq!(error.code(), "NOT_OWNER");
+        assert_eq!(error.to_string(), "View key is not the owner of the record");
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_malformed() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        for ciphertext in ["", "garbage", &OWNER_CIPHERTEXT[..OWNER_CIPHERTEXT.len() - 8], RECORD_PLAINTEXT] {
+            let error = view_key.decrypt(ciphertext).unwrap_err();
+            assert!(matches!(error, DecryptError::MalformedCiphertext(_)), "{error}");
+            assert_eq!(error.code(), "MALFORMED_CIPHERTEXT");
+            assert!(error.to_string().starts_with("Malformed record ciphertext"));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_decrypt_error_to_js() {
+        for error in [DecryptError::NotOwner, DecryptError::MalformedCiphertext("bad".to_string())] {
+            let js_error = JsValue::from(error.clone());
+            assert!(js_error.is_instance_of::<js_sys::Error>());
+            assert_eq!(Reflect::get(&js_error, &"code".into()).unwrap(), error.code());
+            assert_eq!(Reflect::get(&js_error, &"message".into()).unwrap(), error.to_string());
+        }
+    }
 }
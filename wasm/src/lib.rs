@@ -182,6 +182,10 @@ extern "C" {
     // Log a &str the console in the browser or console.log in nodejs
     #[wasm_bindgen(js_namespace = console)]
     pub fn log(s: &str);
+
+    // Log a warning to the console in the browser or console.warn in nodejs
+    #[wasm_bindgen(js_namespace = console)]
+    pub fn warn(s: &str);
 }
 
 /// A trait providing convenient methods for accessing the amount of Aleo present in a record
@@ -214,12 +218,81 @@ impl Credits for RecordPlaintextNative {
 pub use thread_pool::run_rayon_thread;
 use types::native;
 
+/// Initialize the module by installing a panic hook which forwards Rust panics to the console as
+/// errors. This is not done implicitly by any other function, so applications wanting readable
+/// panic messages should call it once at startup. Calling it more than once has no effect
+#[wasm_bindgen(js_name = "init")]
+pub fn init() {
+    console_error_panic_hook::set_once();
+}
+
+/// Check the core invariants of the module, returning the first one which fails. This can be
+/// called at startup to detect a broken build before it is used. The checks are that a private key
+/// round trips through its string and seed encodings, that signatures verify only for the signed
+/// message, and that an encrypted private key decrypts to the original key
+///
+/// @returns {void | Error} Nothing if all checks pass, or an error describing the first failure
+#[wasm_bindgen(js_name = "selfTest")]
+pub fn self_test() -> Result<(), String> {
+    let private_key = PrivateKey::new();
+    if PrivateKey::from_string(&private_key.to_string())? != private_key {
+        return Err("Self test failed: private key string round trip".to_string());
+    }
+    let seed = [7u8; 32];
+    if PrivateKey::from_seed(&seed)? != PrivateKey::from_seed(&seed)? {
+        return Err("Self test failed: private key seed round trip".to_string());
+    }
+
+    let signature = private_key.sign(b"self test");
+    if !signature.verify(&private_key.to_address(), b"self test") {
+        return Err("Self test failed: signature did not verify".to_string());
+    }
+    if signature.verify(&private_key.to_address(), b"other message") {
+        return Err("Self test failed: signature verified for the wrong message".to_string());
+    }
+
+    let ciphertext = PrivateKeyCiphertext::encrypt_private_key(&private_key, "self test")?;
+    if ciphertext.decrypt_to_private_key("self test")? != private_key {
+        return Err("Self test failed: private key encryption round trip".to_string());
+    }
+    Ok(())
+}
+
 #[cfg(not(test))]
 #[wasm_bindgen(js_name = "initThreadPool")]
 pub async fn init_thread_pool(url: web_sys::Url, num_threads: usize) -> Result<(), JsValue> {
-    console_error_panic_hook::set_once();
-
     ThreadPool::builder().url(url).num_threads(num_threads).build_global().await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    // Sign and verify a message to exercise the core account functionality
+    fn sign_and_verify() -> bool {
+        let private_key = PrivateKey::new();
+        let signature = Signature::sign(&private_key, b"message");
+        signature.verify(&private_key.to_address(), b"message")
+    }
+
+    #[wasm_bindgen_test]
+    fn test_self_test() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_core_functions_work_with_and_without_init() {
+        assert!(sign_and_verify());
+
+        init();
+        assert!(sign_and_verify());
+
+        // Check calling init again has no effect.
+        init();
+        assert!(sign_and_verify());
+    }
+}
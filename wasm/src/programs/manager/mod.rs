@@ -70,6 +70,21 @@ impl ProgramManager {
         Ok(microcredits)
     }
 
+    /// Compute a fee consisting of a base cost plus a per-byte cost, returning an error instead of
+    /// silently wrapping if the computation overflows
+    ///
+    /// @param base {u64} The base cost in microcredits
+    /// @param per_byte {u64} The cost per byte in microcredits
+    /// @param byte_count {u64} The number of bytes to charge for
+    /// @returns {u64 | Error} The total fee in microcredits
+    #[wasm_bindgen(js_name = "computeFee")]
+    pub fn compute_fee(base: u64, per_byte: u64, byte_count: u64) -> Result<u64, String> {
+        per_byte
+            .checked_mul(byte_count)
+            .and_then(|byte_cost| byte_cost.checked_add(base))
+            .ok_or_else(|| "The fee computation overflowed".to_string())
+    }
+
     /// Synthesize proving and verifying keys for a program
     ///
     /// @param program {string} The program source code of the program to synthesize keys for
@@ -226,4 +241,18 @@ function add_and_double:
         assert_eq!(double_import, &double_program);
         assert!(main_program.is_err());
     }
+
+    #[wasm_bindgen_test]
+    fn test_compute_fee() {
+        assert_eq!(ProgramManager::compute_fee(1_000, 10, 250).unwrap(), 3_500);
+        assert_eq!(ProgramManager::compute_fee(1_000, 0, 250).unwrap(), 1_000);
+        assert_eq!(ProgramManager::compute_fee(0, 1, u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_compute_fee_overflow() {
+        assert!(ProgramManager::compute_fee(0, 2, u64::MAX).is_err());
+        assert!(ProgramManager::compute_fee(1, 1, u64::MAX).is_err());
+        assert!(ProgramManager::compute_fee(u64::MAX, u64::MAX, u64::MAX).is_err());
+    }
 }
@@ -17,9 +17,11 @@
 mod credits;
 pub use credits::*;
 
-use crate::types::native::{FromBytes, ProvingKeyNative, ToBytes};
+use crate::types::{
+    native::{FromBytes, ProvingKeyNative, ToBytes},
+    sha256_hex,
+};
 
-use sha2::Digest;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use std::{ops::Deref, str::FromStr};
@@ -35,7 +37,7 @@ impl ProvingKey {
     ///
     /// @returns {string} Checksum of the proving key
     pub fn checksum(&self) -> String {
-        hex::encode(sha2::Sha256::digest(self.to_bytes().unwrap()))
+        sha256_hex(&self.to_bytes().unwrap())
     }
 
     /// Create a copy of the proving key
@@ -17,9 +17,11 @@
 mod credits;
 pub use credits::*;
 
-use crate::types::native::{FromBytes, ToBytes, VerifyingKeyNative};
+use crate::types::{
+    native::{FromBytes, ToBytes, VerifyingKeyNative},
+    sha256_hex,
+};
 
-use sha2::Digest;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 use std::{ops::Deref, str::FromStr};
@@ -35,7 +37,7 @@ impl VerifyingKey {
     ///
     /// @returns {string} Checksum of the verifying key
     pub fn checksum(&self) -> String {
-        hex::encode(sha2::Sha256::digest(self.to_bytes().unwrap()))
+        sha256_hex(&self.to_bytes().unwrap())
     }
 
     /// Create a copy of the verifying key
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::{account::Address, Credits};
+
+use crate::types::native::{Entry, LiteralNative, PlaintextNative, RecordPlaintextNative};
+use std::ops::Deref;
+use wasm_bindgen::prelude::*;
+
+/// Plaintext of a record following the `credits.aleo/credits` record schema, which contains a
+/// private `microcredits` entry and no other entries
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct CreditsRecord(RecordPlaintextNative);
+
+#[wasm_bindgen]
+impl CreditsRecord {
+    /// Create a credits record from a record plaintext, checking the record matches the
+    /// `credits.aleo/credits` record schema
+    ///
+    /// @param {RecordPlaintext} record Record plaintext to convert
+    /// @returns {CreditsRecord | Error} Credits record, or an error if the record is not a credits record
+    #[wasm_bindgen(js_name = fromRecord)]
+    pub fn from_record(record: &RecordPlaintext) -> Result<CreditsRecord, String> {
+        let mut entries = record.data().iter();
+        match (entries.next(), entries.next()) {
+            (Some((identifier, Entry::Private(PlaintextNative::Literal(LiteralNative::U64(_), _)))), None)
+                if identifier.to_string() == "microcredits" =>
+            {
+                Ok(Self(record.deref().clone()))
+            }
+            _ => Err("The record does not match the credits.aleo/credits record schema".to_string()),
+        }
+    }
+
+    /// Returns the owner of the credits record
+    ///
+    /// @returns {Address} Address of the owner of the record
+    pub fn owner(&self) -> Address {
+        Address::from(**self.0.owner())
+    }
+
+    /// Returns the amount of microcredits in the credits record
+    ///
+    /// @returns {u64} Amount of microcredits in the record
+    pub fn microcredits(&self) -> u64 {
+        self.0.microcredits().unwrap_or(0)
+    }
+
+    /// Returns the nonce of the credits record
+    ///
+    /// @returns {string} Nonce of the record
+    pub fn nonce(&self) -> String {
+        self.0.nonce().to_string()
+    }
+}
+
+impl Deref for CreditsRecord {
+    type Target = RecordPlaintextNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    const RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+    const OWNER: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    #[wasm_bindgen_test]
+    fn test_from_record() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let credits = CreditsRecord::from_record(&record).unwrap();
+        assert_eq!(credits.owner().to_string(), OWNER);
+        assert_eq!(credits.microcredits(), 1500000000000000);
+        assert_eq!(credits.nonce(), record.nonce());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_non_credits_record() {
+        let token = format!("{{ owner: {OWNER}.private, amount: 100u64.private, _nonce: 0group.public }}");
+        assert!(CreditsRecord::from_record(&RecordPlaintext::from_string(&token).unwrap()).is_err());
+
+        // Check extra entries and public or mistyped microcredits are rejected.
+        let extra = format!(
+            "{{ owner: {OWNER}.private, microcredits: 100u64.private, memo: 1field.private, _nonce: 0group.public }}"
+        );
+        assert!(CreditsRecord::from_record(&RecordPlaintext::from_string(&extra).unwrap()).is_err());
+        let public = RECORD.replace("u64.private", "u64.public");
+        assert!(CreditsRecord::from_record(&RecordPlaintext::from_string(&public).unwrap()).is_err());
+        let mistyped = RECORD.replace("1500000000000000u64", "15u32");
+        assert!(CreditsRecord::from_record(&RecordPlaintext::from_string(&mistyped).unwrap()).is_err());
+    }
+}
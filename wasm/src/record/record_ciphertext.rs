@@ -17,11 +17,19 @@
 use super::RecordPlaintext;
 use crate::account::ViewKey;
 
-use crate::types::native::RecordCiphertextNative;
+use crate::types::native::{CurrentNetwork, FieldNative, Network, RecordCiphertextNative};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
 /// Encrypted Aleo record
+///
+/// Record ciphertexts cannot be re-randomized without decrypting them. Each entry of a record is
+/// encrypted with a key derived by hashing the record view key `(nonce * view_key).x`, which equals
+/// `(randomizer * address).x`. Moving to a fresh nonce changes the record view key, and because the
+/// entry keys are hashes of it, the existing ciphertext cannot be adjusted to the new keys without
+/// recovering the plaintext. A relay would also need the owner's address, which is itself encrypted
+/// in records with a private owner. Re-randomization must therefore be done by the owner by
+/// decrypting the record and encrypting it again under a new randomizer.
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct RecordCiphertext(RecordCiphertextNative);
@@ -65,6 +73,17 @@ impl RecordCiphertext {
     pub fn is_owner(&self, view_key: &ViewKey) -> bool {
         self.0.is_owner(view_key)
     }
+
+    /// Get a deterministic public tag for the record ciphertext which can be used by relays to
+    /// route or index encrypted records. The tag is a hash of the public nonce of the record, so it
+    /// can be computed without any key and reveals nothing about the owner or contents of the record
+    ///
+    /// @returns {string} Routing tag of the record as a field element
+    #[wasm_bindgen(js_name = routingTag)]
+    pub fn routing_tag(&self) -> String {
+        let domain = FieldNative::new_domain_separator("AleoRecordRoutingTag");
+        CurrentNetwork::hash_psd2(&[domain, self.0.nonce().to_x_coordinate()]).unwrap().to_string()
+    }
 }
 
 impl FromStr for RecordCiphertext {
@@ -83,6 +102,17 @@ impl Deref for RecordCiphertext {
     }
 }
 
+// Encrypt a record with the given entries to an owner under a fresh randomizer, for use in tests
+#[cfg(test)]
+pub(crate) fn encrypt_record(owner: &crate::account::Address, entries: &str) -> RecordCiphertext {
+    use crate::types::native::{RecordPlaintextNative, ScalarNative, Uniform};
+
+    let randomizer = ScalarNative::rand(&mut rand::thread_rng());
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    let record = format!("{{ owner: {owner}.private, {entries}, _nonce: {nonce}.public }}");
+    RecordCiphertext(RecordPlaintextNative::from_str(&record).unwrap().encrypt(randomizer).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +132,14 @@ mod tests {
     const _OWNER_PRIVATE_KEY: &str = "APrivateKey1zkpJkyYRGYtkeHDaFfwsKtUJzia7csiWhfBWPXWhXJzy9Ls";
     const _OWNER_ADDRESS: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
 
+    // Encrypt the owner plaintext under a fresh randomizer, returning the plaintext and its ciphertext
+    fn encrypt_owner_plaintext() -> (String, RecordCiphertext) {
+        let owner = ViewKey::from_string(OWNER_VIEW_KEY).unwrap().to_address();
+        let ciphertext = encrypt_record(&owner, "microcredits: 1500000000000000u64.private");
+        let original_nonce = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap().nonce().to_string();
+        (OWNER_PLAINTEXT.replace(&original_nonce, &ciphertext.nonce().to_string()), ciphertext)
+    }
+
     #[wasm_bindgen_test]
     fn test_to_and_from_string() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
@@ -121,19 +159,49 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_decrypt() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         let plaintext = record.decrypt(&view_key).unwrap();
         assert_eq!(plaintext.to_string(), OWNER_PLAINTEXT);
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         assert!(record.decrypt(&incorrect_view_key).is_err());
     }
 
     #[wasm_bindgen_test]
     fn test_is_owner() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         assert!(record.is_owner(&view_key));
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         assert!(!record.is_owner(&incorrect_view_key));
     }
+
+    #[wasm_bindgen_test]
+    fn test_routing_tag() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let tag = record.routing_tag();
+        assert!(tag.ends_with("field"));
+
+        // Check the tag is stable for the same ciphertext.
+        assert_eq!(tag, record.routing_tag());
+        assert_eq!(tag, RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap().routing_tag());
+
+        // Check a record with a different nonce has a different tag.
+        let (_, other) = encrypt_owner_plaintext();
+        assert_ne!(tag, other.routing_tag());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encrypt_and_decrypt_round_trip() {
+        let (plaintext, ciphertext) = encrypt_owner_plaintext();
+
+        // Check the owner detects and decrypts the record to the original plaintext.
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        assert!(ciphertext.is_owner(&view_key));
+        assert_eq!(ciphertext.decrypt(&view_key).unwrap().to_string(), plaintext);
+
+        // Check a non-owner can neither detect nor decrypt the record.
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
+        assert!(!ciphertext.is_owner(&incorrect_view_key));
+        assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
+    }
 }
@@ -14,12 +14,40 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{account::PrivateKey, types::Field, Credits};
+use crate::{
+    account::{Address, PrivateKey, ViewKey},
+    types::Field,
+    Credits,
+};
 
-use crate::types::native::{IdentifierNative, ProgramIDNative, RecordPlaintextNative};
-use std::{ops::Deref, str::FromStr};
+use crate::types::native::{
+    CurrentNetwork,
+    Entry,
+    FieldNative,
+    IdentifierNative,
+    LiteralNative,
+    Network,
+    OwnerNative,
+    PlaintextNative,
+    PrivateKeyNative,
+    ProgramIDNative,
+    RecordPlaintextNative,
+    ScalarNative,
+    Uniform,
+};
+use indexmap::IndexSet;
+use js_sys::{Array, BigInt, Object, Reflect};
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+use rayon::prelude::*;
+use std::{collections::HashSet, ops::Deref, str::FromStr, sync::Once};
 use wasm_bindgen::prelude::*;
 
+// Ensures the deprecation warning for `gates` is only logged once
+static GATES_DEPRECATION: Once = Once::new();
+
+/// Default maximum size in bytes of a record string accepted by `RecordPlaintext::from_string`
+const DEFAULT_MAX_RECORD_DATA_BYTES: usize = 64 * 1024;
+
 /// Plaintext representation of an Aleo record
 #[wasm_bindgen]
 #[derive(Clone)]
@@ -27,6 +55,14 @@ pub struct RecordPlaintext(RecordPlaintextNative);
 
 #[wasm_bindgen]
 impl RecordPlaintext {
+    /// Compute the commitment of the record for a program record type. This is the commitment
+    /// stored on chain when the record is created, so explorers and indexers can look records up
+    /// without deriving their serial numbers. Call `toString` on the result for its field string
+    ///
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {Field | Error} Commitment of the record, or an error if the program ID or record
+    /// name is invalid
     #[wasm_bindgen]
     pub fn commitment(&self, program_id: &str, record_name: &str) -> Result<Field, String> {
         Ok(Field::from(
@@ -40,13 +76,53 @@ impl RecordPlaintext {
         ))
     }
 
-    /// Return a record plaintext from a string.
+    /// Return a record plaintext from a string. Underscore digit separators in numeric literals,
+    /// i.e. `1_000u64`, are accepted and removed before parsing. Strings larger than 64 KiB are
+    /// rejected before they are parsed, as in `fromStringWithLimit`
     ///
     /// @param {string} record String representation of a plaintext representation of an Aleo record
     /// @returns {RecordPlaintext | Error} Record plaintext
     #[wasm_bindgen(js_name = fromString)]
     pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
-        Self::from_str(record).map_err(|_| "The record plaintext string provided was invalid".into())
+        Self::from_string_with_limit(record, None)
+    }
+
+    /// Parse a list of record plaintexts from newline-delimited text containing one record per line.
+    /// Blank lines are skipped, and each line is parsed as by `fromString`
+    ///
+    /// @param {string} text Newline-delimited record plaintext strings
+    /// @returns {RecordPlaintext[] | Error} Record plaintexts, or an error reporting the line number
+    /// of the first record which could not be parsed
+    #[wasm_bindgen(js_name = fromLines)]
+    pub fn from_lines(text: &str) -> Result<Vec<RecordPlaintext>, String> {
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                Self::from_string(line.trim()).map_err(|_| format!("Invalid record plaintext on line {}", index + 1))
+            })
+            .collect()
+    }
+
+    /// Return a record plaintext from a string, rejecting strings larger than a maximum number of
+    /// bytes before they are parsed. This guards against malformed or hostile input causing deep
+    /// recursion or large allocations while parsing deeply nested or very large record data
+    ///
+    /// @param {string} record String representation of a plaintext representation of an Aleo record
+    /// @param {number | undefined} max_data_bytes Maximum size of the record string in bytes,
+    /// defaulting to 64 KiB
+    /// @returns {RecordPlaintext | Error} Record plaintext
+    #[wasm_bindgen(js_name = fromStringWithLimit)]
+    pub fn from_string_with_limit(record: &str, max_data_bytes: Option<usize>) -> Result<RecordPlaintext, String> {
+        let max_data_bytes = max_data_bytes.unwrap_or(DEFAULT_MAX_RECORD_DATA_BYTES);
+        if record.len() > max_data_bytes {
+            return Err(format!(
+                "The record plaintext string is {} bytes which exceeds the limit of {max_data_bytes} bytes",
+                record.len()
+            ));
+        }
+        Self::from_str(&strip_digit_separators(record))
+            .map_err(|_| "The record plaintext string provided was invalid".into())
     }
 
     /// Returns the record plaintext string
@@ -58,6 +134,29 @@ impl RecordPlaintext {
         self.0.to_string()
     }
 
+    /// Returns the record plaintext string using the specified number of spaces for each level of
+    /// indentation. The output can be parsed back into a record with `fromString`
+    ///
+    /// @param {number} indent Number of spaces to indent each level of the record by
+    /// @returns {string} String representation of the record plaintext
+    #[wasm_bindgen(js_name = toStringPretty)]
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let padding = " ".repeat(indent);
+        let mut output = format!("{{\n{padding}owner: {},\n", self.0.owner());
+        for (identifier, entry) in self.data() {
+            let (plaintext, visibility) = match entry {
+                Entry::Constant(plaintext) => (plaintext, "constant"),
+                Entry::Public(plaintext) => (plaintext, "public"),
+                Entry::Private(plaintext) => (plaintext, "private"),
+            };
+            output.push_str(&format!("{padding}{identifier}: "));
+            Self::write_plaintext(&mut output, plaintext, visibility, 1, indent);
+            output.push_str(",\n");
+        }
+        output.push_str(&format!("{padding}_nonce: {}.public\n}}", self.nonce()));
+        output
+    }
+
     /// Returns the amount of microcredits in the record
     ///
     /// @returns {u64} Amount of microcredits in the record
@@ -65,6 +164,147 @@ impl RecordPlaintext {
         self.0.microcredits().unwrap_or(0)
     }
 
+    /// Returns the amount of microcredits in the record. Deprecated: records store their value in
+    /// the `microcredits` field, use `microcredits()` instead
+    ///
+    /// @returns {u64} Amount of microcredits in the record
+    pub fn gates(&self) -> u64 {
+        GATES_DEPRECATION.call_once(|| {
+            crate::warn("RecordPlaintext.gates() is deprecated and will be removed, use microcredits() instead")
+        });
+        self.microcredits()
+    }
+
+    /// Get the value of a named entry in the record, such as the balance of a token record stored
+    /// under a program specific name
+    ///
+    /// @param {string} name Name of the record entry
+    /// @returns {string | Error} String representation of the plaintext value of the entry, without
+    /// its visibility, or an error if the record does not contain the entry
+    #[wasm_bindgen(js_name = getMember)]
+    pub fn get_member(&self, name: &str) -> Result<String, String> {
+        self.member(name).map(|plaintext| plaintext.to_string())
+    }
+
+    /// Get the value of an entry in the record using a dotted path. Struct members are accessed by
+    /// name and array elements by index, i.e. `data.position.x` or `data.values.0`
+    ///
+    /// @param {string} path Dotted path to the value, beginning with the name of the record entry
+    /// @returns {string | Object | Array | Error} String representation of a literal value, or an
+    /// object or array for struct and array values
+    #[wasm_bindgen(js_name = entryNested)]
+    pub fn entry_nested(&self, path: &str) -> Result<JsValue, String> {
+        let mut segments = path.split('.');
+        let mut plaintext = self.member(segments.next().unwrap_or_default())?;
+        for segment in segments {
+            plaintext = match plaintext {
+                PlaintextNative::Struct(members, ..) => {
+                    let identifier = IdentifierNative::from_str(segment)
+                        .map_err(|_| format!("{segment} is an invalid identifier"))?;
+                    members
+                        .get(&identifier)
+                        .ok_or_else(|| format!("{path} does not contain a member named {segment}"))?
+                }
+                PlaintextNative::Array(elements, ..) => {
+                    let index = usize::from_str(segment).map_err(|_| format!("{segment} is an invalid array index"))?;
+                    elements.get(index).ok_or_else(|| format!("{path} does not contain an element at index {index}"))?
+                }
+                PlaintextNative::Literal(..) => return Err(format!("{path} cannot be accessed within a literal")),
+            };
+        }
+        Self::plaintext_to_js(plaintext)
+    }
+
+    /// Ensure the amount of microcredits in the record does not exceed a maximum supply. This can be
+    /// used to catch corrupted decryptions before the record is used
+    ///
+    /// @param {bigint} max_supply Maximum number of microcredits a valid record may contain
+    /// @returns {void | Error} Nothing if the record value is valid, an error otherwise
+    #[wasm_bindgen(js_name = validateValue)]
+    pub fn validate_value(&self, max_supply: u64) -> Result<(), String> {
+        let microcredits = self.0.microcredits()?;
+        if microcredits > max_supply {
+            return Err(format!(
+                "The record contains {microcredits} microcredits which exceeds the maximum supply of {max_supply}"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ensure every entry of the record uses a type supported by the current network build. Records
+    /// containing types unknown to this build already fail to parse, so this catches literal types
+    /// which parse but cannot be declared in a record, such as strings
+    ///
+    /// @returns {void | Error} Nothing if every entry is supported, or an error naming the path of
+    /// the first unsupported value
+    #[wasm_bindgen(js_name = checkSupported)]
+    pub fn check_supported(&self) -> Result<(), String> {
+        for (identifier, entry) in self.data() {
+            let (Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) = entry;
+            Self::check_supported_plaintext(&identifier.to_string(), plaintext)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the address of the owner of the record
+    ///
+    /// @returns {Address} Address of the owner of the record
+    pub fn owner(&self) -> Address {
+        Address::from(**self.0.owner())
+    }
+
+    /// Determine whether a view key owns the record, which requires the address derived from the
+    /// view key to be the owner of the record. Wallets can use this to confirm a decrypted record
+    /// belongs to the scanning view key
+    ///
+    /// @param {ViewKey} view_key View key to check
+    /// @returns {boolean} True if the view key owns the record, false otherwise
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.owner() == view_key.to_address()
+    }
+
+    /// Returns the visibility of the owner of the record. UIs can use this to warn users when the
+    /// owner of a record is publicly visible
+    ///
+    /// @returns {string} Visibility of the owner, either "private" or "public"
+    #[wasm_bindgen(js_name = ownerVisibility)]
+    pub fn owner_visibility(&self) -> String {
+        if self.0.owner().is_public() { "public".to_string() } else { "private".to_string() }
+    }
+
+    /// Determine whether a private key can spend the record, which requires the address derived
+    /// from the private key to be the owner of the record
+    ///
+    /// @param {PrivateKey} private_key Private key to check
+    /// @returns {boolean} True if the private key can spend the record, false otherwise
+    #[wasm_bindgen(js_name = isSpendableBy)]
+    pub fn is_spendable_by(&self, private_key: &PrivateKey) -> bool {
+        self.owner() == private_key.to_address()
+    }
+
+    /// Re-own the record to a recipient and encrypt it under a fresh nonce, producing a ciphertext
+    /// the recipient can decrypt with their view key. The entries of the record are unchanged and
+    /// the owner keeps its visibility. The gifted record is not committed to the ledger, so it is
+    /// only useful for sharing record contents off-chain and cannot be spent
+    ///
+    /// @param {Address} recipient Address of the new owner of the record
+    /// @returns {string | Error} Ciphertext of the gifted record
+    #[wasm_bindgen(js_name = giftTo)]
+    pub fn gift_to(&self, recipient: &Address) -> Result<String, String> {
+        let owner = if self.0.owner().is_public() {
+            OwnerNative::Public(**recipient)
+        } else {
+            OwnerNative::Private(PlaintextNative::from(LiteralNative::Address(**recipient)))
+        };
+        let randomizer = ScalarNative::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = RecordPlaintextNative::from_plaintext(owner, self.0.data().clone(), nonce)
+            .map_err(|_| "Failed to re-own the record".to_string())?;
+        let ciphertext = record.encrypt(randomizer).map_err(|_| "Failed to encrypt the record".to_string())?;
+        Ok(ciphertext.to_string())
+    }
+
     /// Returns the nonce of the record. This can be used to uniquely identify a record.
     ///
     /// @returns {string} Nonce of the record
@@ -92,6 +332,355 @@ impl RecordPlaintext {
             .map_err(|_| "Serial number derivation failed".to_string())?;
         Ok(serial_number.to_string())
     }
+
+    /// Get the serial numbers of many records of the same program record type in a single call.
+    /// The program ID and record name are only parsed once. When the crate is built with the
+    /// `parallel` feature for a target other than wasm, the serial numbers are derived concurrently
+    /// with rayon; otherwise, including in the browser, they are derived sequentially
+    ///
+    /// @param {RecordPlaintext[]} records Records owned by the private key
+    /// @param {PrivateKey} private_key Private key of the account that owns the records
+    /// @param {string} program_id Program ID of the program that the records are associated with
+    /// @param {string} record_name Name of the records
+    /// @returns {string[] | Error} Serial number of each record, in the same order as the records
+    #[wasm_bindgen(js_name = serialNumbersBatch)]
+    pub fn serial_numbers_batch(
+        records: Vec<RecordPlaintext>,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<Vec<String>, String> {
+        let program_id =
+            ProgramIDNative::from_str(program_id).map_err(|_| format!("{program_id} is an invalid program name"))?;
+        let record_name =
+            IdentifierNative::from_str(record_name).map_err(|_| format!("{record_name} is an invalid identifier"))?;
+        let private_key = PrivateKeyNative::from(private_key);
+
+        let serial_number = |record: &RecordPlaintext| -> Result<String, String> {
+            let commitment = record.to_commitment(&program_id, &record_name).map_err(|e| e.to_string())?;
+            RecordPlaintextNative::serial_number(private_key, commitment)
+                .map(|serial_number| serial_number.to_string())
+                .map_err(|_| "Serial number derivation failed".to_string())
+        };
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        let records = records.par_iter();
+        #[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+        let records = records.iter();
+        records.map(serial_number).collect()
+    }
+
+    /// Get the record together with its serial number in the form required to spend it as the
+    /// input of a transition
+    ///
+    /// @param {PrivateKey} private_key Private key of the account that owns the record
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {Object | Error} Object of the form `{ record, serialNumber }`
+    #[wasm_bindgen(js_name = toSpendInput)]
+    pub fn to_spend_input(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<JsValue, String> {
+        let serial_number = self.serial_number_string(private_key, program_id, record_name)?;
+        let spend_input = Object::new();
+        Reflect::set(&spend_input, &"record".into(), &self.to_string().into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&spend_input, &"serialNumber".into(), &serial_number.into())
+            .map_err(|_| "Failed to set property")?;
+        Ok(spend_input.into())
+    }
+
+    /// Determine if two record plaintexts represent the same record. Unlike comparing the output of
+    /// `toString`, this compares the owner, entries, and nonce of the records directly, so records
+    /// parsed from differently formatted strings are considered equal
+    ///
+    /// @param {RecordPlaintext} other Record plaintext to compare against
+    /// @returns {boolean} True if the records are the same, false otherwise
+    #[wasm_bindgen(js_name = semanticallyEquals)]
+    pub fn semantically_equals(&self, other: &RecordPlaintext) -> bool {
+        self.0 == other.0
+    }
+
+    /// Get the fields which differ between this record, taken as the expected record, and another
+    /// record, taken as the actual record. This is intended for debugging records which do not
+    /// decrypt to the expected values
+    ///
+    /// @param {RecordPlaintext} other Actual record to compare against
+    /// @returns {Object | Error} Object of the form `{ field: { expected, actual } }` for each field
+    /// which differs, where a field missing from one of the records is `undefined`
+    pub fn diff(&self, other: &RecordPlaintext) -> Result<JsValue, String> {
+        let diff = Object::new();
+        let add_difference = |field: &str, expected: Option<String>, actual: Option<String>| {
+            if expected == actual {
+                return Ok(());
+            }
+            let difference = Object::new();
+            Reflect::set(&difference, &"expected".into(), &expected.into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&difference, &"actual".into(), &actual.into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&diff, &field.into(), &difference).map_err(|_| "Failed to set property")?;
+            Ok::<(), String>(())
+        };
+
+        add_difference("owner", Some(self.0.owner().to_string()), Some(other.0.owner().to_string()))?;
+        let names = self.data().keys().chain(other.data().keys()).collect::<IndexSet<_>>();
+        for name in names {
+            add_difference(
+                &name.to_string(),
+                self.data().get(name).map(|entry| entry.to_string()),
+                other.data().get(name).map(|entry| entry.to_string()),
+            )?;
+        }
+        add_difference("_nonce", Some(self.nonce()), Some(other.nonce()))?;
+        Ok(diff.into())
+    }
+}
+
+// Remove underscore digit separators from numeric literals, i.e. `1_000u64` becomes `1000u64`. Only
+// underscores inside words starting with a digit are removed, so identifiers such as `_nonce` or
+// `token_1` are left unchanged
+fn strip_digit_separators(record: &str) -> String {
+    let mut normalized = String::with_capacity(record.len());
+    let mut in_word = false;
+    let mut in_number = false;
+    for c in record.chars() {
+        let is_word_char = c.is_ascii_alphanumeric() || c == '_';
+        if is_word_char && !in_word {
+            in_number = c.is_ascii_digit();
+        }
+        in_word = is_word_char;
+        if !(in_number && c == '_') {
+            normalized.push(c);
+        }
+    }
+    normalized
+}
+
+/// Filter a list of records to those containing at least a minimum amount of microcredits. Records
+/// without a microcredits field are skipped
+///
+/// @param {RecordPlaintext[]} records Records to filter
+/// @param {bigint} min_microcredits Minimum amount of microcredits a record must contain
+/// @returns {RecordPlaintext[]} Records containing at least the minimum amount of microcredits
+#[wasm_bindgen(js_name = "filterRecordsMinValue")]
+pub fn filter_records_min_value(records: Vec<RecordPlaintext>, min_microcredits: u64) -> Vec<RecordPlaintext> {
+    records
+        .into_iter()
+        .filter(|record| record.0.microcredits().map_or(false, |microcredits| microcredits >= min_microcredits))
+        .collect()
+}
+
+/// Select records whose combined microcredits cover a target amount. If a single record covers the
+/// target, the smallest such record is chosen to minimize the change. Otherwise records are taken
+/// from largest to smallest until the target is reached. Records without a microcredits field are
+/// skipped
+///
+/// @param {RecordPlaintext[]} records Records to select from
+/// @param {bigint} target Amount of microcredits the selected records must cover
+/// @returns {RecordPlaintext[] | Error} Selected records, or an error if the records cannot cover the target
+#[wasm_bindgen(js_name = "selectRecords")]
+pub fn select_records(records: Vec<RecordPlaintext>, target: u64) -> Result<Vec<RecordPlaintext>, String> {
+    if target == 0 {
+        return Ok(vec![]);
+    }
+    let mut records = records
+        .into_iter()
+        .filter_map(|record| record.0.microcredits().ok().map(|microcredits| (microcredits, record)))
+        .collect::<Vec<(u64, RecordPlaintext)>>();
+    records.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    // Prefer the smallest single record which covers the target.
+    if let Some(index) = records.iter().rposition(|(microcredits, _)| *microcredits >= target) {
+        return Ok(vec![records.swap_remove(index).1]);
+    }
+
+    let mut total = 0u64;
+    let mut selected = vec![];
+    for (microcredits, record) in records {
+        total = total.saturating_add(microcredits);
+        selected.push(record);
+        if total >= target {
+            return Ok(selected);
+        }
+    }
+    Err(format!("The records provided contain {total} microcredits which is insufficient to cover {target}"))
+}
+
+/// Preview spending an amount plus a fee from a set of records. Records are selected as in
+/// `selectRecords`, and the change left over after paying the amount and fee is computed
+///
+/// @param {RecordPlaintext[]} records Records available to spend
+/// @param {bigint} amount Amount of microcredits to spend
+/// @param {bigint} fee Fee in microcredits to pay
+/// @returns {Object | Error} Object of the form `{ selected, totalInput, change, sufficient }`. If
+/// the records cannot cover the amount and fee, no records are selected and `sufficient` is false
+#[wasm_bindgen(js_name = "simulateSpend")]
+pub fn simulate_spend(records: Vec<RecordPlaintext>, amount: u64, fee: u64) -> Result<JsValue, String> {
+    let target = amount.checked_add(fee).ok_or_else(|| "The amount plus the fee overflowed".to_string())?;
+    let (selected, sufficient) = match select_records(records, target) {
+        Ok(selected) => (selected, true),
+        Err(_) => (vec![], false),
+    };
+    let total_input = selected
+        .iter()
+        .try_fold(0u64, |total, record| total.checked_add(record.microcredits()))
+        .ok_or_else(|| "The total of the selected records overflowed".to_string())?;
+    let change = if sufficient { total_input - target } else { 0 };
+
+    let simulation = Object::new();
+    let selected = selected.into_iter().map(JsValue::from).collect::<Array>();
+    Reflect::set(&simulation, &"selected".into(), &selected).map_err(|_| "Failed to set property")?;
+    Reflect::set(&simulation, &"totalInput".into(), &BigInt::from(total_input).into())
+        .map_err(|_| "Failed to set property")?;
+    Reflect::set(&simulation, &"change".into(), &BigInt::from(change).into()).map_err(|_| "Failed to set property")?;
+    Reflect::set(&simulation, &"sufficient".into(), &sufficient.into()).map_err(|_| "Failed to set property")?;
+    Ok(simulation.into())
+}
+
+/// Find the nonces which appear in more than one record. Records are uniquely identified by their
+/// nonce, so duplicates indicate a node returned the same record twice or returned a forged record
+///
+/// @param {RecordPlaintext[]} records Records to check for duplicate nonces
+/// @returns {string[]} Nonces which appear in more than one record
+#[wasm_bindgen(js_name = "detectDuplicateNonces")]
+pub fn detect_duplicate_nonces(records: Vec<RecordPlaintext>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates = IndexSet::new();
+    for record in records {
+        let nonce = record.nonce();
+        if !seen.insert(nonce.clone()) {
+            duplicates.insert(nonce);
+        }
+    }
+    duplicates.into_iter().collect()
+}
+
+/// Get the distinct owners of a list of records, in the order each owner first appears
+///
+/// @param {RecordPlaintext[]} records Records to get the owners of
+/// @returns {Address[]} Distinct owners of the records
+#[wasm_bindgen(js_name = "distinctOwners")]
+pub fn distinct_owners(records: Vec<RecordPlaintext>) -> Vec<Address> {
+    records.iter().map(|record| *record.owner()).collect::<IndexSet<_>>().into_iter().map(Address::from).collect()
+}
+
+/// Check that each record in a list matches the commitment it is expected to have before the
+/// records are spent, such as the commitments of the records as found on chain. A record whose
+/// owner, data, or nonce has been altered no longer matches its commitment
+///
+/// @param {RecordPlaintext[]} records Records to validate
+/// @param {string} program_id Program ID of the program that the records are associated with
+/// @param {string} record_name Name of the record type
+/// @param {string[]} commitments Expected commitment of each record, in the same order as the records
+/// @returns {Array | Error} Array of booleans indicating whether each record matches its expected
+/// commitment. An error is returned if the program ID, record name, or a commitment is invalid, or
+/// if the number of commitments does not match the number of records
+#[wasm_bindgen(js_name = "validateRecords")]
+pub fn validate_records(
+    records: Vec<RecordPlaintext>,
+    program_id: &str,
+    record_name: &str,
+    commitments: Vec<String>,
+) -> Result<Array, String> {
+    if records.len() != commitments.len() {
+        return Err(format!("Expected {} commitments, found {}", records.len(), commitments.len()));
+    }
+    let program_id =
+        ProgramIDNative::from_str(program_id).map_err(|_| format!("{program_id} is an invalid program name"))?;
+    let record_name =
+        IdentifierNative::from_str(record_name).map_err(|_| format!("{record_name} is an invalid identifier"))?;
+    let results = Array::new_with_length(records.len() as u32);
+    for (index, (record, commitment)) in records.iter().zip(commitments.iter()).enumerate() {
+        let commitment =
+            FieldNative::from_str(commitment).map_err(|_| format!("{commitment} is an invalid commitment"))?;
+        let valid = record.0.to_commitment(&program_id, &record_name).map_or(false, |actual| actual == commitment);
+        results.set(index as u32, valid.into());
+    }
+    Ok(results)
+}
+
+impl RecordPlaintext {
+    // Get the plaintext value of a named entry in the record
+    fn member(&self, name: &str) -> Result<&PlaintextNative, String> {
+        let identifier = IdentifierNative::from_str(name).map_err(|_| format!("{name} is an invalid identifier"))?;
+        match self.data().get(&identifier) {
+            Some(Entry::Constant(plaintext)) | Some(Entry::Public(plaintext)) | Some(Entry::Private(plaintext)) => {
+                Ok(plaintext)
+            }
+            None => Err(format!("The record does not contain an entry named {name}")),
+        }
+    }
+
+    // Convert a plaintext value into a javascript value
+    fn plaintext_to_js(plaintext: &PlaintextNative) -> Result<JsValue, String> {
+        match plaintext {
+            PlaintextNative::Literal(literal, ..) => Ok(JsValue::from_str(&literal.to_string())),
+            PlaintextNative::Struct(members, ..) => {
+                let object = Object::new();
+                for (identifier, member) in members {
+                    Reflect::set(&object, &identifier.to_string().into(), &Self::plaintext_to_js(member)?)
+                        .map_err(|_| "Failed to set property")?;
+                }
+                Ok(object.into())
+            }
+            PlaintextNative::Array(elements, ..) => {
+                let array = Array::new_with_length(elements.len() as u32);
+                for (index, element) in elements.iter().enumerate() {
+                    array.set(index as u32, Self::plaintext_to_js(element)?);
+                }
+                Ok(array.into())
+            }
+        }
+    }
+
+    // Ensure a plaintext value and any values nested within it use supported types
+    fn check_supported_plaintext(path: &str, plaintext: &PlaintextNative) -> Result<(), String> {
+        match plaintext {
+            PlaintextNative::Literal(LiteralNative::String(..), ..) => {
+                Err(format!("{path} is a string, which is not supported in records"))
+            }
+            PlaintextNative::Literal(..) => Ok(()),
+            PlaintextNative::Struct(members, ..) => members.iter().try_for_each(|(identifier, member)| {
+                Self::check_supported_plaintext(&format!("{path}.{identifier}"), member)
+            }),
+            PlaintextNative::Array(elements, ..) => elements
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, element)| Self::check_supported_plaintext(&format!("{path}.{index}"), element)),
+        }
+    }
+
+    // Write a plaintext value with the given visibility at the given depth of indentation
+    fn write_plaintext(
+        output: &mut String,
+        plaintext: &PlaintextNative,
+        visibility: &str,
+        depth: usize,
+        indent: usize,
+    ) {
+        let padding = " ".repeat(indent * (depth + 1));
+        match plaintext {
+            PlaintextNative::Literal(literal, ..) => output.push_str(&format!("{literal}.{visibility}")),
+            PlaintextNative::Struct(members, ..) => {
+                output.push_str("{\n");
+                for (index, (identifier, member)) in members.iter().enumerate() {
+                    output.push_str(&format!("{padding}{identifier}: "));
+                    Self::write_plaintext(output, member, visibility, depth + 1, indent);
+                    output.push_str(if index + 1 < members.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&format!("{}}}", " ".repeat(indent * depth)));
+            }
+            PlaintextNative::Array(elements, ..) => {
+                output.push_str("[\n");
+                for (index, element) in elements.iter().enumerate() {
+                    output.push_str(&padding);
+                    Self::write_plaintext(output, element, visibility, depth + 1, indent);
+                    output.push_str(if index + 1 < elements.len() { ",\n" } else { "\n" });
+                }
+                output.push_str(&format!("{}]", " ".repeat(indent * depth)));
+            }
+        }
+    }
 }
 
 impl From<RecordPlaintextNative> for RecordPlaintext {
@@ -128,18 +717,365 @@ mod tests {
   _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
 }";
 
+    const OWNER: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+    const PRIVATE_KEY: &str = "APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE";
+    const OWNER_VIEW_KEY: &str = "AViewKey1ccEt8A2Ryva5rxnKcAbn7wgTaTsb79tzkKHFpeKsm9NX";
+    const NON_OWNER_VIEW_KEY: &str = "AViewKey1e2WyreaH5H4RBcioLL2GnxvHk5Ud46EtwycnhTdXLmXp";
+
+    fn credits_record(microcredits: u64) -> RecordPlaintext {
+        let record =
+            format!("{{ owner: {OWNER}.private, microcredits: {microcredits}u64.private, _nonce: 0group.public }}");
+        RecordPlaintext::from_string(&record).unwrap()
+    }
+
     #[wasm_bindgen_test]
     fn test_to_and_from_string() {
         let record = RecordPlaintext::from_string(RECORD).unwrap();
         assert_eq!(record.to_string(), RECORD);
     }
 
+    #[wasm_bindgen_test]
+    fn test_nonce() {
+        let record = credits_record(5);
+        assert_eq!(record.nonce(), "0group");
+
+        // Check the nonce survives a round trip through its string representation.
+        let round_trip = RecordPlaintext::from_string(&record.to_string()).unwrap();
+        assert_eq!(round_trip.nonce(), "0group");
+        assert!(RECORD.contains(&format!("_nonce: {}.public", RecordPlaintext::from_string(RECORD).unwrap().nonce())));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_string_with_digit_separators() {
+        let record = RecordPlaintext::from_string(&format!(
+            "{{ owner: {OWNER}.private, microcredits: 1_000u64.private, token_1: 2_000_000u128.public, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        assert_eq!(record.microcredits(), 1000);
+        assert!(record.to_string().contains("token_1: 2000000u128.public"));
+        assert_eq!(record.nonce(), "0group");
+
+        // Check underscores outside of numeric literals are preserved.
+        assert_eq!(strip_digit_separators("{ _nonce: 1_0u8, a_1: -2_5i8 }"), "{ _nonce: 10u8, a_1: -25i8 }");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_string_pretty() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.to_string_pretty(2), RECORD);
+
+        // Ensure records with nested structs round trip for each indentation
+        let nested = &format!(
+            "{{ owner: {OWNER}.private, position: {{ x: 1u32.private, y: {{ z: 2u32.private }} }}, amount: 5u64.public, _nonce: 0group.public }}"
+        );
+        for record in [record, RecordPlaintext::from_string(nested).unwrap()] {
+            for indent in [0, 2, 4] {
+                let pretty = record.to_string_pretty(indent);
+                let parsed = RecordPlaintext::from_string(&pretty).unwrap();
+                assert!(record.semantically_equals(&parsed));
+            }
+        }
+    }
+
     #[wasm_bindgen_test]
     fn test_microcredits_from_string() {
         let record = RecordPlaintext::from_string(RECORD).unwrap();
         assert_eq!(record.microcredits(), 1500000000000000);
     }
 
+    #[wasm_bindgen_test]
+    fn test_gates() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.gates(), 1500000000000000);
+        // Check repeated calls still return the value after the warning has been logged.
+        assert_eq!(record.gates(), record.microcredits());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_member() {
+        let token = format!(
+            "{{ owner: {OWNER}.private, amount: 100u64.private, decimals: 6u8.public, _nonce: 0group.public }}"
+        );
+        let record = RecordPlaintext::from_string(&token).unwrap();
+        assert_eq!(record.get_member("amount").unwrap(), "100u64");
+        assert_eq!(record.get_member("decimals").unwrap(), "6u8");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_member_missing() {
+        let record = credits_record(5);
+        assert_eq!(record.get_member("amount").unwrap_err(), "The record does not contain an entry named amount");
+        assert!(record.get_member("not an identifier").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_entry_nested() {
+        let nested = &format!(
+            "{{ owner: {OWNER}.private, data: {{ position: {{ x: 1u32.private, y: 2u32.private }}, values: [ 3u8.private, 4u8.private ] }}, _nonce: 0group.public }}"
+        );
+        let record = RecordPlaintext::from_string(nested).unwrap();
+
+        // Check literals are returned as strings
+        assert_eq!(record.entry_nested("data.position.x").unwrap().as_string().unwrap(), "1u32");
+        assert_eq!(record.entry_nested("data.values.1").unwrap().as_string().unwrap(), "4u8");
+
+        // Check structs are returned as objects
+        let position = record.entry_nested("data.position").unwrap();
+        assert_eq!(Reflect::get(&position, &"y".into()).unwrap().as_string().unwrap(), "2u32");
+
+        // Check invalid paths return errors
+        assert!(record.entry_nested("data.position.z").is_err());
+        assert!(record.entry_nested("data.values.2").is_err());
+        assert!(record.entry_nested("data.position.x.y").is_err());
+        assert!(record.entry_nested("missing").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_check_supported() {
+        assert!(RecordPlaintext::from_string(RECORD).unwrap().check_supported().is_ok());
+        let nested = format!(
+            "{{ owner: {OWNER}.private, data: {{ position: {{ x: 1u32.private, y: 2i8.private }}, values: [ 3field.private, true.private ] }}, _nonce: 0group.public }}"
+        );
+        assert!(RecordPlaintext::from_string(&nested).unwrap().check_supported().is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_check_supported_string() {
+        let memo = format!("{{ owner: {OWNER}.private, memo: \"hello\".private, _nonce: 0group.public }}");
+        let record = RecordPlaintext::from_string(&memo).unwrap();
+        assert_eq!(record.check_supported().unwrap_err(), "memo is a string, which is not supported in records");
+
+        let nested =
+            format!("{{ owner: {OWNER}.private, data: {{ notes: [ \"a\".private ] }}, _nonce: 0group.public }}");
+        let record = RecordPlaintext::from_string(&nested).unwrap();
+        assert_eq!(
+            record.check_supported().unwrap_err(),
+            "data.notes.0 is a string, which is not supported in records"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_value() {
+        let max_supply = 1_500_000_000_000_000;
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert!(record.validate_value(max_supply).is_ok());
+
+        // Ensure an absurdly large value is rejected
+        let corrupted = &format!(
+            "{{ owner: {OWNER}.private, microcredits: 18446744073709551615u64.private, _nonce: 0group.public }}"
+        );
+        let record = RecordPlaintext::from_string(corrupted).unwrap();
+        assert!(record.validate_value(max_supply).is_err());
+
+        // Ensure records without a microcredits field are rejected
+        let token = &format!("{{ owner: {OWNER}.private, amount: 5u64.private, _nonce: 0group.public }}");
+        let record = RecordPlaintext::from_string(token).unwrap();
+        assert!(record.validate_value(max_supply).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_filter_records_min_value() {
+        let token = &format!("{{ owner: {OWNER}.private, amount: 500u64.private, _nonce: 0group.public }}");
+        let records = vec![
+            credits_record(99),
+            credits_record(100),
+            RecordPlaintext::from_string(token).unwrap(),
+            credits_record(101),
+            credits_record(0),
+        ];
+        let filtered = filter_records_min_value(records, 100);
+        let values = filtered.iter().map(|record| record.microcredits()).collect::<Vec<u64>>();
+        assert_eq!(values, vec![100, 101]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_select_records() {
+        let microcredits =
+            |records: Vec<RecordPlaintext>| records.iter().map(|r| r.microcredits()).collect::<Vec<u64>>();
+
+        // Check an exact single record match is chosen.
+        let records = vec![credits_record(20), credits_record(50), credits_record(30)];
+        assert_eq!(microcredits(select_records(records, 50).unwrap()), vec![50]);
+
+        // Check the smallest record covering the target is chosen.
+        let records = vec![credits_record(10), credits_record(100), credits_record(70)];
+        assert_eq!(microcredits(select_records(records, 60).unwrap()), vec![70]);
+
+        // Check multiple records are combined when no single record covers the target.
+        let records = vec![credits_record(20), credits_record(40), credits_record(30)];
+        assert_eq!(microcredits(select_records(records, 60).unwrap()), vec![40, 30]);
+
+        // Check an insufficient total is an error.
+        let records = vec![credits_record(10), credits_record(20)];
+        assert!(select_records(records, 50).is_err());
+        assert!(select_records(vec![], 1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_simulate_spend() {
+        let records = vec![credits_record(20), credits_record(40), credits_record(30)];
+        let simulation = simulate_spend(records, 50, 10).unwrap();
+        let selected = Array::from(&Reflect::get(&simulation, &"selected".into()).unwrap());
+        assert_eq!(selected.length(), 2);
+        assert_eq!(Reflect::get(&simulation, &"totalInput".into()).unwrap(), JsValue::from(BigInt::from(70u64)));
+        assert_eq!(Reflect::get(&simulation, &"change".into()).unwrap(), JsValue::from(BigInt::from(10u64)));
+        assert_eq!(Reflect::get(&simulation, &"sufficient".into()).unwrap(), JsValue::TRUE);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_simulate_spend_insufficient() {
+        let records = vec![credits_record(20), credits_record(40)];
+        let simulation = simulate_spend(records, 50, 20).unwrap();
+        let selected = Array::from(&Reflect::get(&simulation, &"selected".into()).unwrap());
+        assert_eq!(selected.length(), 0);
+        assert_eq!(Reflect::get(&simulation, &"change".into()).unwrap(), JsValue::from(BigInt::from(0u64)));
+        assert_eq!(Reflect::get(&simulation, &"sufficient".into()).unwrap(), JsValue::FALSE);
+
+        // Check an overflowing amount and fee is an error.
+        assert!(simulate_spend(vec![credits_record(20)], u64::MAX, 1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_detect_duplicate_nonces() {
+        let unique = vec![RecordPlaintext::from_string(RECORD).unwrap(), credits_record(5)];
+        assert!(detect_duplicate_nonces(unique).is_empty());
+
+        let duplicated = vec![
+            credits_record(5),
+            RecordPlaintext::from_string(RECORD).unwrap(),
+            credits_record(7),
+            credits_record(9),
+        ];
+        assert_eq!(detect_duplicate_nonces(duplicated), vec!["0group".to_string()]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_distinct_owners() {
+        let other = PrivateKey::new().to_address();
+        let other_record = RecordPlaintext::from_string(&format!(
+            "{{ owner: {other}.private, microcredits: 5u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let records = vec![credits_record(5), other_record.clone(), credits_record(7), other_record];
+
+        let owners = distinct_owners(records).iter().map(|owner| owner.to_string()).collect::<Vec<_>>();
+        assert_eq!(owners, vec![OWNER.to_string(), other.to_string()]);
+        assert!(distinct_owners(vec![]).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_records() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let other = RecordPlaintext::from_string(&RECORD.replace("1500000000000000u64", "5u64")).unwrap();
+        let commitments = [&record, &other]
+            .iter()
+            .map(|record| record.commitment("credits.aleo", "credits").unwrap().to_string())
+            .collect::<Vec<_>>();
+        let valid =
+            validate_records(vec![record.clone(), other.clone()], "credits.aleo", "credits", commitments.clone())
+                .unwrap();
+        assert_eq!(valid.to_vec(), vec![JsValue::TRUE, JsValue::TRUE]);
+
+        // Check records with a tampered amount or nonce no longer match their commitments.
+        let inflated = RecordPlaintext::from_string(&RECORD.replace("1500000000000000u64", "1500000000000001u64"));
+        let renonced = RecordPlaintext::from_string(&RECORD.replace(&record.nonce(), "0group"));
+        let tampered = vec![inflated.unwrap(), renonced.unwrap(), other.clone()];
+        let expected = vec![commitments[0].clone(), commitments[0].clone(), commitments[1].clone()];
+        let valid = validate_records(tampered, "credits.aleo", "credits", expected).unwrap();
+        assert_eq!(valid.to_vec(), vec![JsValue::FALSE, JsValue::FALSE, JsValue::TRUE]);
+
+        // Check records validated against each other's commitments are reported as invalid.
+        let swapped = vec![commitments[1].clone(), commitments[0].clone()];
+        let valid = validate_records(vec![record.clone(), other], "credits.aleo", "credits", swapped).unwrap();
+        assert_eq!(valid.to_vec(), vec![JsValue::FALSE, JsValue::FALSE]);
+
+        // Check an invalid program ID, an invalid commitment, or a missing commitment fails the whole batch.
+        let single = vec![commitments[0].clone()];
+        assert!(validate_records(vec![record.clone()], "not a real program id", "credits", single).is_err());
+        assert!(validate_records(vec![record.clone()], "credits.aleo", "credits", vec!["1u64".to_string()]).is_err());
+        assert!(validate_records(vec![record], "credits.aleo", "credits", vec![]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_owner_visibility() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.owner_visibility(), "private");
+
+        let public_record = RecordPlaintext::from_string(&RECORD.replacen(".private", ".public", 1)).unwrap();
+        assert_eq!(public_record.owner_visibility(), "public");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_owner() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.owner().to_string(), OWNER);
+        assert_eq!(record.owner(), Address::from_string(OWNER).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_is_owner() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let owner_view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let other_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
+        assert!(record.is_owner(&owner_view_key));
+        assert!(!record.is_owner(&other_view_key));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_is_spendable_by() {
+        let private_key = PrivateKey::new();
+        let record = RecordPlaintext::from_string(&format!(
+            "{{ owner: {}.private, microcredits: 5u64.private, _nonce: 0group.public }}",
+            private_key.to_address()
+        ))
+        .unwrap();
+        assert!(record.is_spendable_by(&private_key));
+
+        // Check a key which does not derive the owner's address cannot spend the record.
+        assert!(!record.is_spendable_by(&PrivateKey::new()));
+        assert!(!credits_record(5).is_spendable_by(&private_key));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_gift_to() {
+        let recipient = PrivateKey::new();
+        let view_key = recipient.to_view_key();
+        let ciphertext = RecordPlaintext::from_string(RECORD).unwrap().gift_to(&recipient.to_address()).unwrap();
+
+        let gifted = RecordPlaintext::from_string(&view_key.decrypt(&ciphertext).unwrap()).unwrap();
+        assert!(gifted.is_spendable_by(&recipient));
+        assert_eq!(gifted.owner_visibility(), "private");
+        assert_eq!(gifted.microcredits(), 1500000000000000);
+        assert_ne!(gifted.nonce(), RecordPlaintext::from_string(RECORD).unwrap().nonce());
+
+        // Check the original owner can no longer decrypt the gifted record.
+        let owner_view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        assert!(owner_view_key.decrypt(&ciphertext).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_commitment() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let commitment = record.commitment("token.aleo", "token").unwrap().to_string();
+        assert!(commitment.ends_with("field"));
+
+        // Check the commitment is stable and depends on the program record type.
+        assert_eq!(record.commitment("token.aleo", "token").unwrap().to_string(), commitment);
+        assert_eq!(
+            RecordPlaintext::from_string(RECORD).unwrap().commitment("token.aleo", "token").unwrap().to_string(),
+            commitment
+        );
+        assert_ne!(record.commitment("credits.aleo", "credits").unwrap().to_string(), commitment);
+        assert_ne!(record.commitment("token.aleo", "coin").unwrap().to_string(), commitment);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_commitment_invalid_program_id() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.commitment("token", "token").unwrap_err(), "token is an invalid program name");
+        assert!(record.commitment("token.aleo", "not a name").is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -151,6 +1087,44 @@ mod tests {
         assert_eq!(expected_sn, result.unwrap());
     }
 
+    #[wasm_bindgen_test]
+    fn test_serial_numbers_batch() {
+        let pk = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        let mut records = vec![RecordPlaintext::from_string(RECORD).unwrap()];
+        records.extend((1..8).map(credits_record));
+
+        let serial_numbers =
+            RecordPlaintext::serial_numbers_batch(records.clone(), &pk, "credits.aleo", "credits").unwrap();
+        let expected = records
+            .iter()
+            .map(|record| record.serial_number_string(&pk, "credits.aleo", "credits").unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(serial_numbers, expected);
+        assert_eq!(
+            serial_numbers[0],
+            "8170619507075647151199239049653235187042661744691458644751012032123701508940field"
+        );
+
+        assert!(RecordPlaintext::serial_numbers_batch(vec![], &pk, "credits.aleo", "credits").unwrap().is_empty());
+        assert!(RecordPlaintext::serial_numbers_batch(records.clone(), &pk, "credits", "credits").is_err());
+        assert!(RecordPlaintext::serial_numbers_batch(records, &pk, "credits.aleo", "not a name").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_spend_input() {
+        let pk = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let expected_sn = "8170619507075647151199239049653235187042661744691458644751012032123701508940field";
+        let spend_input = record.to_spend_input(&pk, "credits.aleo", "credits").unwrap();
+        let serial_number = Reflect::get(&spend_input, &"serialNumber".into()).unwrap();
+        let spent_record = Reflect::get(&spend_input, &"record".into()).unwrap();
+        assert_eq!(serial_number.as_string().unwrap(), expected_sn);
+        assert_eq!(spent_record.as_string().unwrap(), record.to_string());
+
+        // Check invalid program ids are reported as an error.
+        assert!(record.to_spend_input(&pk, "not a real program id", "credits").is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number_can_run_twice_with_same_private_key() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -171,6 +1145,14 @@ mod tests {
         assert!(record.serial_number_string(&pk, program_id, record_name).is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn test_serial_number_invalid_program_id_returns_specific_error() {
+        let pk = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let result = record.serial_number_string(&pk, "not a real program id", "credits");
+        assert_eq!(result.err(), Some("not a real program id is an invalid program name".to_string()));
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number_invalid_record_name_returns_err_string() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -180,6 +1162,87 @@ mod tests {
         assert!(record.serial_number_string(&pk, program_id, record_name).is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn test_diff() {
+        let expected = credits_record(5);
+        let actual = credits_record(7);
+        let diff = expected.diff(&actual).unwrap();
+        assert_eq!(Object::keys(&diff.clone().into()).length(), 1);
+        let microcredits = Reflect::get(&diff, &"microcredits".into()).unwrap();
+        assert_eq!(Reflect::get(&microcredits, &"expected".into()).unwrap().as_string().unwrap(), "5u64.private");
+        assert_eq!(Reflect::get(&microcredits, &"actual".into()).unwrap().as_string().unwrap(), "7u64.private");
+
+        // Check identical records have no differences.
+        assert_eq!(Object::keys(&expected.diff(&credits_record(5)).unwrap().into()).length(), 0);
+
+        // Check fields missing from one record are reported as undefined.
+        let extra = RecordPlaintext::from_string(&format!(
+            "{{ owner: {OWNER}.private, microcredits: 5u64.private, memo: 1field.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let memo = Reflect::get(&expected.diff(&extra).unwrap(), &"memo".into()).unwrap();
+        assert!(Reflect::get(&memo, &"expected".into()).unwrap().is_undefined());
+        assert_eq!(Reflect::get(&memo, &"actual".into()).unwrap().as_string().unwrap(), "1field.private");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_semantically_equals_ignores_formatting() {
+        let compact = &format!(
+            "{{ owner: {OWNER}.private, microcredits: 1500000000000000u64.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }}"
+        );
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let compact_record = RecordPlaintext::from_string(compact).unwrap();
+        assert_ne!(RECORD, compact);
+        assert!(record.semantically_equals(&compact_record));
+
+        // Ensure records with different values are not considered equal
+        let different = &format!(
+            "{{ owner: {OWNER}.private, microcredits: 1u64.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }}"
+        );
+        let different_record = RecordPlaintext::from_string(different).unwrap();
+        assert!(!record.semantically_equals(&different_record));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_lines() {
+        let compact = RECORD.replace('\n', " ");
+        let text = format!("{compact}\n\n  \n{}\n", credits_record(5).to_string().replace('\n', " "));
+        let records = RecordPlaintext::from_lines(&text).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].to_string(), RECORD);
+        assert_eq!(records[1].microcredits(), 5);
+        assert!(RecordPlaintext::from_lines("").unwrap().is_empty());
+
+        // Check digit separators are accepted on each line, as by from_string.
+        let separated = credits_record(1_500).to_string().replace('\n', " ").replace("1500u64", "1_500u64");
+        let records = RecordPlaintext::from_lines(&format!("{compact}\n{separated}")).unwrap();
+        assert_eq!(records[1].microcredits(), 1_500);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_lines_bad_line() {
+        let compact = RECORD.replace('\n', " ");
+        let text = format!("{compact}\n\n{{ owner: not_an_address.private }}\n{compact}");
+        assert_eq!(RecordPlaintext::from_lines(&text).err(), Some("Invalid record plaintext on line 3".to_string()));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_string_with_limit() {
+        let record = RecordPlaintext::from_string_with_limit(RECORD, None).unwrap();
+        assert_eq!(record.to_string(), RECORD);
+        assert!(RecordPlaintext::from_string_with_limit(RECORD, Some(RECORD.len())).is_ok());
+
+        // Check records larger than the limit are rejected before parsing.
+        assert!(RecordPlaintext::from_string_with_limit(RECORD, Some(RECORD.len() - 1)).is_err());
+        let entries = (0..5_000).map(|i| format!("field_{i}: {i}u64.private")).collect::<Vec<_>>().join(", ");
+        let oversized = format!("{{ owner: {OWNER}.private, {entries}, _nonce: 0group.public }}");
+        let error = RecordPlaintext::from_string_with_limit(&oversized, None).err().unwrap();
+        assert!(error.contains("exceeds the limit"));
+
+        // Check the default limit also applies to from_string.
+        assert_eq!(RecordPlaintext::from_string(&oversized).err().unwrap(), error);
+    }
+
     #[wasm_bindgen_test]
     fn test_bad_inputs_to_from_string() {
         let invalid_bech32 = "{ owner: aleo2d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: 99u64.public, _nonce: 0group.public }";
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sha2::Sha256;
+use sha3::{Digest, Sha3_256};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Length in characters of the tags produced by `memo_tag`
+const MEMO_TAG_LEN: usize = 8;
+
+/// Get the SHA3-256 digest of a byte array as a lowercase hex string. This is the digest used by
+/// the SDK for content addressing, so it can be used to hash arbitrary payloads consistently.
+/// Proving and verifying key checksums are SHA-256 digests instead, so that they match the
+/// checksums published in the snarkVM parameter metadata
+///
+/// @param {Uint8Array} bytes Bytes to hash
+/// @returns {string} Lowercase hex encoding of the SHA3-256 digest
+#[wasm_bindgen(js_name = "digestHex")]
+pub fn digest_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha3_256::digest(bytes))
+}
+
+// Get the SHA-256 digest of a byte array as a lowercase hex string, as used for key checksums
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Get a short tag identifying a message, suitable for attaching a reference to a payment. The tag
+/// is the first 8 characters of the URL safe base64 encoding of the SHA3-256 digest of the UTF-8
+/// encoded message, so the same message always produces the same tag. Tags are too short to be
+/// collision resistant and must not be used to authenticate a message
+///
+/// @param {string} message Message to tag
+/// @returns {string} 8 character URL safe tag of the message
+#[wasm_bindgen(js_name = "memoTag")]
+pub fn memo_tag(message: &str) -> String {
+    let mut tag = URL_SAFE_NO_PAD.encode(Sha3_256::digest(message.as_bytes()));
+    tag.truncate(MEMO_TAG_LEN);
+    tag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_digest_hex() {
+        assert_eq!(digest_hex(b""), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        assert_eq!(digest_hex(b"abc"), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+
+        // Check key checksums use SHA-256 rather than the SHA3-256 content digest.
+        assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_memo_tag() {
+        assert_eq!(memo_tag("invoice #42"), "-JkpX25h");
+        assert_eq!(memo_tag(""), "p__G-L8e");
+        assert_eq!(memo_tag("invoice #42"), memo_tag("invoice #42"));
+        assert_ne!(memo_tag("invoice #42"), memo_tag("invoice #43"));
+
+        // Check tags are a fixed length and URL safe regardless of the message.
+        for message in ["", "a", "rent 🏠", &"x".repeat(10_000)] {
+            let tag = memo_tag(message);
+            assert_eq!(tag.len(), MEMO_TAG_LEN);
+            assert!(tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        }
+    }
+}
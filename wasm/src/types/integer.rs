@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Parse a u64 from either its Aleo literal form (i.e. `99u64`) or its plain decimal form (i.e.
+/// `99`). Literals of any other type (i.e. `99u32` or `99field`) are rejected
+///
+/// @param {string} literal Aleo u64 literal or decimal string
+/// @returns {bigint | Error} Value of the literal
+#[wasm_bindgen(js_name = "parseU64Literal")]
+pub fn parse_u64_literal(literal: &str) -> Result<u64, String> {
+    let digits = literal.strip_suffix("u64").unwrap_or(literal);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(format!("Invalid u64 literal: {literal}"));
+    }
+    digits.parse::<u64>().map_err(|_| format!("Value of {literal} does not fit in a u64"))
+}
+
+/// Get the Aleo literal form of a u64 (i.e. `99u64`)
+///
+/// @param {bigint} value Value to convert
+/// @returns {string} Aleo u64 literal
+#[wasm_bindgen(js_name = "toU64Literal")]
+pub fn to_u64_literal(value: u64) -> String {
+    format!("{value}u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_u64_literal() {
+        assert_eq!(parse_u64_literal("99u64").unwrap(), 99);
+        assert_eq!(parse_u64_literal("99").unwrap(), 99);
+        assert_eq!(parse_u64_literal("0u64").unwrap(), 0);
+        assert_eq!(parse_u64_literal("18446744073709551615u64").unwrap(), u64::MAX);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_u64_literal_rejects_invalid_forms() {
+        assert!(parse_u64_literal("99u32").is_err());
+        assert!(parse_u64_literal("99i64").is_err());
+        assert!(parse_u64_literal("99field").is_err());
+        assert!(parse_u64_literal("-99").is_err());
+        assert!(parse_u64_literal("+99").is_err());
+        assert!(parse_u64_literal("u64").is_err());
+        assert!(parse_u64_literal("").is_err());
+        assert!(parse_u64_literal("18446744073709551616u64").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_u64_literal() {
+        assert_eq!(to_u64_literal(99), "99u64");
+        assert_eq!(to_u64_literal(u64::MAX), "18446744073709551615u64");
+        assert_eq!(parse_u64_literal(&to_u64_literal(1_500_000)).unwrap(), 1_500_000);
+    }
+}
@@ -14,7 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod digest;
+pub use digest::*;
+
 pub mod field;
 pub use field::*;
 
+pub mod integer;
+pub use integer::*;
+
 pub(crate) mod native;
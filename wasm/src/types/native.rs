@@ -18,12 +18,14 @@ pub use snarkvm_circuit_network::{Aleo, AleoV0};
 pub use snarkvm_console::{
     account::{Address, PrivateKey, Signature, ViewKey},
     network::{Network, Testnet3},
+    prelude::{FromBits, SizeInDataBits, ToBits, Zero},
     program::{
         Ciphertext,
         Entry,
         EntryType,
         Identifier,
         Literal,
+        Owner,
         Plaintext,
         PlaintextType,
         ProgramID,
@@ -32,7 +34,7 @@ pub use snarkvm_console::{
         Response,
         ValueType,
     },
-    types::Field,
+    types::{Field, Group, Scalar},
 };
 pub use snarkvm_ledger_block::{Execution, Transaction};
 pub use snarkvm_ledger_query::Query;
@@ -59,6 +61,8 @@ pub type ViewKeyNative = ViewKey<CurrentNetwork>;
 
 // Algebraic types
 pub type FieldNative = Field<CurrentNetwork>;
+pub type GroupNative = Group<CurrentNetwork>;
+pub type ScalarNative = Scalar<CurrentNetwork>;
 
 // Network types
 pub type CurrentNetwork = Testnet3;
@@ -75,6 +79,7 @@ type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
 pub type ExecutionNative = Execution<CurrentNetwork>;
 pub type IdentifierNative = Identifier<CurrentNetwork>;
 pub type LiteralNative = Literal<CurrentNetwork>;
+pub type OwnerNative = Owner<CurrentNetwork, PlaintextNative>;
 pub type ProcessNative = Process<CurrentNetwork>;
 pub type ProgramIDNative = ProgramID<CurrentNetwork>;
 pub type ProgramNative = Program<CurrentNetwork>;